@@ -19,6 +19,15 @@ pub enum LcAppError {
 
     #[error("Language does not exist for question {0}")]
     LanguageDoesNotExistError(String),
+
+    #[error("There is no official solution for {0}.")]
+    NoOfficialSolution(String),
+
+    #[error("The official solution of {0} is only available with LeetCode Premium.")]
+    PremiumRequired(String),
+
+    #[error("This solution no longer exists.")]
+    SolutionNotFound,
 }
 
 pub type AppResult<T> = Result<T, LcAppError>;
@@ -0,0 +1,53 @@
+use super::GQLLeetcodeRequest;
+use serde::Serialize;
+
+const QUERY: &str = r#"
+query communitySolution($topicId: Int!) {
+  topic(id: $topicId) {
+    id
+    title
+    solutionTags {
+      name
+      slug
+    }
+    post {
+      voteCount
+      content
+      author {
+        username
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Variables {
+    topic_id: u32,
+}
+
+/// Article of a community solution, by the id listed with it.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    query: &'static str,
+    variables: Variables,
+}
+
+impl Query {
+    pub fn new(topic_id: u32) -> Self {
+        Self {
+            query: QUERY,
+            variables: Variables { topic_id },
+        }
+    }
+}
+
+impl GQLLeetcodeRequest for Query {
+    type T = crate::types::community_solutions::TopicData;
+
+    fn use_cache(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,72 @@
+use super::GQLLeetcodeRequest;
+use serde::Serialize;
+
+const QUERY: &str = r#"
+query communitySolutions($questionSlug: String!, $skip: Int!, $first: Int!, $query: String, $orderBy: TopicSortingOption, $languageTags: [String!], $topicTags: [String!]) {
+  questionSolutions(
+    filters: {questionSlug: $questionSlug, skip: $skip, first: $first, query: $query, orderBy: $orderBy, languageTags: $languageTags, topicTags: $topicTags}
+  ) {
+    totalNum
+    solutions {
+      id
+      title
+      solutionTags {
+        name
+        slug
+      }
+      post {
+        voteCount
+        author {
+          username
+        }
+      }
+    }
+  }
+}
+"#;
+
+/// Number of solutions fetched at once.
+pub const PAGE_SIZE: u32 = 20;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Variables {
+    question_slug: String,
+    skip: u32,
+    first: u32,
+    query: String,
+    order_by: &'static str,
+    language_tags: Vec<String>,
+    topic_tags: Vec<String>,
+}
+
+/// A page of the community solutions of a question, most voted first.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    query: &'static str,
+    variables: Variables,
+}
+
+impl Query {
+    /// Solutions written in `language_tag`, e.g. `python3`, or in any
+    /// language when `None`, starting after the `skip` most voted ones.
+    pub fn new(question_slug: String, language_tag: Option<String>, skip: u32) -> Self {
+        Self {
+            query: QUERY,
+            variables: Variables {
+                question_slug,
+                skip,
+                first: PAGE_SIZE,
+                query: String::new(),
+                order_by: "most_votes",
+                language_tags: language_tag.into_iter().collect(),
+                topic_tags: vec![],
+            },
+        }
+    }
+}
+
+impl GQLLeetcodeRequest for Query {
+    type T = crate::types::community_solutions::Data;
+}
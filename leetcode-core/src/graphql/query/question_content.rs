@@ -6,6 +6,17 @@ query questionContent($titleSlug: String!) {
   question(titleSlug: $titleSlug) {
     content
     titleSlug
+    hints
+    difficulty
+    likes
+    dislikes
+    topicTags {
+      name
+      id
+      slug
+    }
+    similarQuestions
+    stats
   }
 }
 "#;
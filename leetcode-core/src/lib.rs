@@ -11,4 +11,7 @@ pub use reqwest::Client;
 pub use types::editor_data::QuestionData as EditorDataResponse;
 pub use types::problemset_question_list::Root as QuestionResponse;
 
+pub use graphql::query::community_solution::Query as CommunitySolutionRequest;
+pub use graphql::query::community_solutions::Query as CommunitySolutionsRequest;
+pub use graphql::query::official_solution::Query as OfficialSolutionRequest;
 pub use graphql::query::question_content::Query as QuestionContentRequest;
@@ -0,0 +1,143 @@
+use crate::errors::{AppResult, LcAppError};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct Data {
+    pub data: QueryCommunitySolutions,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryCommunitySolutions {
+    pub question_solutions: QuestionSolutions,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionSolutions {
+    #[serde(default)]
+    pub total_num: u32,
+    pub solutions: Vec<CommunitySolution>,
+}
+
+/// A community solution as listed, without its article.
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommunitySolution {
+    /// Id of the discussion topic holding the article.
+    pub id: u32,
+    pub title: String,
+    #[serde(default)]
+    pub solution_tags: Vec<SolutionTag>,
+    pub post: Post,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SolutionTag {
+    pub name: String,
+    pub slug: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Post {
+    #[serde(default)]
+    pub vote_count: i32,
+    /// Markdown of the article, only fetched with a single solution.
+    #[serde(default)]
+    pub content: Option<String>,
+    /// `None` for deleted accounts.
+    pub author: Option<Author>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct Author {
+    pub username: String,
+}
+
+impl Post {
+    pub fn author(&self) -> &str {
+        self.author
+            .as_ref()
+            .map_or("[deleted]", |author| author.username.as_str())
+    }
+}
+
+impl CommunitySolution {
+    /// Names of the language and topic tags, e.g. `Python3, Hash Table`.
+    pub fn tags(&self) -> String {
+        self.solution_tags
+            .iter()
+            .map(|tag| tag.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct TopicData {
+    pub data: QueryCommunitySolution,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct QueryCommunitySolution {
+    /// `None` once the solution is deleted.
+    pub topic: Option<CommunitySolution>,
+}
+
+impl TopicData {
+    /// Title and markdown of the article, or why it cannot be read.
+    pub fn into_article(self) -> AppResult<(String, String)> {
+        let solution = self.data.topic.ok_or(LcAppError::SolutionNotFound)?;
+        let title = format!("{} by {}", solution.title, solution.post.author());
+        let content = solution.post.content.unwrap_or_default();
+        // older posts come with their line breaks escaped
+        let content = if content.contains('\n') {
+            content
+        } else {
+            content.replace("\\n", "\n").replace("\\t", "\t")
+        };
+        Ok((title, content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Data, TopicData};
+    use crate::errors::LcAppError;
+
+    #[test]
+    fn test_json_deserialization() {
+        let list: Data = serde_json::from_str(
+            r#"{"data": {"questionSolutions": {"totalNum": 2, "solutions": [
+                {"id": 3, "title": "One pass hash map", "solutionTags": [{"name": "Python3", "slug": "python3"}, {"name": "Hash Table", "slug": "hash-table"}],
+                 "post": {"voteCount": 1200, "author": {"username": "alice"}}},
+                {"id": 7, "title": "Brute force", "solutionTags": [], "post": {"voteCount": -2, "author": null}}
+            ]}}}"#,
+        )
+        .unwrap();
+        let solutions = list.data.question_solutions.solutions;
+        assert_eq!(solutions[0].tags(), "Python3, Hash Table");
+        assert_eq!(solutions[0].post.author(), "alice");
+        assert_eq!(solutions[1].post.author(), "[deleted]");
+        assert_eq!(solutions[1].post.vote_count, -2);
+
+        let topic: TopicData = serde_json::from_str(
+            r##"{"data": {"topic": {"id": 3, "title": "One pass hash map", "solutionTags": [],
+                "post": {"voteCount": 1200, "content": "# Approach\\n```python3 []\\nclass Solution: pass\\n```", "author": {"username": "alice"}}}}}"##,
+        )
+        .unwrap();
+        assert_eq!(
+            topic.into_article().unwrap(),
+            (
+                "One pass hash map by alice".into(),
+                "# Approach\n```python3 []\nclass Solution: pass\n```".into()
+            )
+        );
+
+        let deleted: TopicData = serde_json::from_str(r#"{"data": {"topic": null}}"#).unwrap();
+        assert!(matches!(
+            deleted.into_article(),
+            Err(LcAppError::SolutionNotFound)
+        ));
+    }
+}
@@ -2,18 +2,21 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(test)]
 #[derive(Debug, Deserialize, Serialize)]
 struct LanguageInfo {
     id: u32,
     name: String,
 }
 
+#[cfg(test)]
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct Data {
     language_list: Vec<LanguageInfo>,
 }
 
+#[cfg(test)]
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct Languages {
@@ -125,6 +128,37 @@ impl From<Language> for u32 {
 }
 
 impl Language {
+    /// Every known language, in the order of their ids.
+    pub const ALL: [Language; 27] = [
+        Language::Cpp,
+        Language::Java,
+        Language::Python,
+        Language::Mysql,
+        Language::C,
+        Language::Csharp,
+        Language::Javascript,
+        Language::Ruby,
+        Language::Bash,
+        Language::Swift,
+        Language::Golang,
+        Language::Python3,
+        Language::Scala,
+        Language::Kotlin,
+        Language::Mssql,
+        Language::Oraclesql,
+        Language::Html,
+        Language::Pythonml,
+        Language::Rust,
+        Language::Php,
+        Language::Typescript,
+        Language::Racket,
+        Language::Erlang,
+        Language::Elixir,
+        Language::Dart,
+        Language::Pythondata,
+        Language::React,
+    ];
+
     pub fn from_id(id: u32) -> Language {
         match id {
             0 => Language::Cpp,
@@ -280,6 +314,30 @@ impl Language {
             Language::Unknown(_) => "",
         }
     }
+
+    /// Language of a markdown code fence info string such as `python3 []`
+    /// or `C++`. `python` is taken as python3, which is what most articles
+    /// mean by it.
+    pub fn from_code_fence(info: &str) -> Option<Language> {
+        let name = info.split_whitespace().next()?.to_lowercase();
+        let language = match name.as_str() {
+            "c++" => Language::Cpp,
+            "python" | "py" => Language::Python3,
+            "c#" | "cs" => Language::Csharp,
+            "js" => Language::Javascript,
+            "ts" => Language::Typescript,
+            "go" => Language::Golang,
+            "rs" => Language::Rust,
+            "kt" => Language::Kotlin,
+            "rb" => Language::Ruby,
+            "sql" => Language::Mysql,
+            "sh" => Language::Bash,
+            _ => Language::ALL
+                .into_iter()
+                .find(|language| language.to_string() == name)?,
+        };
+        Some(language)
+    }
 }
 
 impl Display for Language {
@@ -386,6 +444,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_code_fence() {
+        assert_eq!(
+            Language::from_code_fence("python3 []"),
+            Some(Language::Python3)
+        );
+        assert_eq!(Language::from_code_fence("Python"), Some(Language::Python3));
+        assert_eq!(Language::from_code_fence("C++ [sol]"), Some(Language::Cpp));
+        assert_eq!(Language::from_code_fence("golang"), Some(Language::Golang));
+        assert_eq!(Language::from_code_fence("text"), None);
+        assert_eq!(Language::from_code_fence(""), None);
+    }
+
+    #[test]
+    fn test_all_languages_are_in_id_order() {
+        for (id, language) in Language::ALL.into_iter().enumerate() {
+            assert_eq!(Language::from_id(id as u32), language);
+            assert_eq!(u32::from(language), id as u32);
+        }
+        assert!(matches!(
+            Language::from_id(Language::ALL.len() as u32),
+            Language::Unknown(_)
+        ));
+    }
+
     use std::collections::HashMap;
     #[test]
     fn test() {
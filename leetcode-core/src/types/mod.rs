@@ -1,6 +1,8 @@
+pub mod community_solutions;
 pub mod console_panel_config;
 pub mod editor_data;
 pub mod language;
+pub mod official_solution;
 pub mod problemset_question_list;
 pub mod question_content;
 pub(crate) mod run;
@@ -0,0 +1,87 @@
+use crate::errors::{AppResult, LcAppError};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct Data {
+    pub data: QueryOfficialSolution,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct QueryOfficialSolution {
+    /// `None` for an unknown title slug.
+    pub question: Option<SolutionQuestion>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SolutionQuestion {
+    pub title: String,
+    pub solution: Option<OfficialSolution>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfficialSolution {
+    pub title: String,
+    /// Markdown of the article, `None` when the account cannot read it.
+    pub content: Option<String>,
+    #[serde(default)]
+    pub paid_only: bool,
+    #[serde(default)]
+    pub can_see_detail: bool,
+}
+
+impl Data {
+    /// Title and markdown of the article, or why it cannot be read.
+    pub fn into_article(self) -> AppResult<(String, String)> {
+        let question = self
+            .data
+            .question
+            .ok_or_else(|| LcAppError::NoOfficialSolution("this question".into()))?;
+        let solution = question
+            .solution
+            .ok_or(LcAppError::NoOfficialSolution(question.title))?;
+        match solution.content {
+            Some(content) if !solution.paid_only || solution.can_see_detail => {
+                Ok((solution.title, content))
+            }
+            _ => Err(LcAppError::PremiumRequired(solution.title)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Data;
+    use crate::errors::LcAppError;
+
+    fn parse(json: &str) -> Data {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_json_deserialization() {
+        let article = parse(
+            r#"{"data": {"question": {"title": "Two Sum", "solution": {
+                "title": "Two Sum", "content": "[TOC]\n## Solution", "paidOnly": false, "canSeeDetail": true
+            }}}}"#,
+        )
+        .into_article()
+        .unwrap();
+        assert_eq!(article, ("Two Sum".into(), "[TOC]\n## Solution".into()));
+
+        let locked = parse(
+            r#"{"data": {"question": {"title": "Paint House", "solution": {
+                "title": "Paint House", "content": null, "paidOnly": true, "canSeeDetail": false
+            }}}}"#,
+        );
+        assert!(matches!(
+            locked.into_article(),
+            Err(LcAppError::PremiumRequired(title)) if title == "Paint House"
+        ));
+
+        let missing = parse(r#"{"data": {"question": {"title": "Two Sum", "solution": null}}}"#);
+        assert!(matches!(
+            missing.into_article(),
+            Err(LcAppError::NoOfficialSolution(_))
+        ));
+    }
+}
@@ -105,7 +105,7 @@ mod tests {
         assert_eq!(question.freq_bar, None);
         assert_eq!(question.frontend_question_id, "6".to_string());
         assert_eq!(question.is_favor, Some(false));
-        assert_eq!(question.paid_only, false);
+        assert!(!question.paid_only);
         assert_eq!(question.status, Some("ac".to_string()));
         assert_eq!(question.title, ("Zigzag Conversion".to_string()));
         assert_eq!(question.title_slug, ("zigzag-conversion".to_string()));
@@ -1,4 +1,8 @@
 use html2text::from_read;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+
+use super::problemset_question_list::TopicTag;
+
 #[derive(Debug, serde::Deserialize)]
 pub struct QueryQuestionContent {
     pub question: QuestionContent,
@@ -9,6 +13,56 @@ pub struct QueryQuestionContent {
 pub struct QuestionContent {
     pub content: String,
     pub title_slug: String,
+    /// Hints of the question as html snippets, spoilers to show on demand.
+    #[serde(default)]
+    pub hints: Vec<String>,
+    #[serde(default)]
+    pub difficulty: String,
+    #[serde(default)]
+    pub likes: u32,
+    #[serde(default)]
+    pub dislikes: u32,
+    #[serde(default)]
+    pub topic_tags: Vec<TopicTag>,
+    /// Sent by the api as a JSON encoded string.
+    #[serde(default, deserialize_with = "from_json_string")]
+    pub similar_questions: Vec<SimilarQuestion>,
+    /// Sent by the api as a JSON encoded string.
+    #[serde(default, deserialize_with = "from_json_string")]
+    pub stats: Option<QuestionStats>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarQuestion {
+    pub title: String,
+    pub title_slug: String,
+    pub difficulty: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionStats {
+    /// Human readable, e.g. `1.2M`.
+    pub total_accepted: String,
+    pub total_submission: String,
+    /// e.g. `52.1%`.
+    pub ac_rate: String,
+}
+
+/// Deserializes a value the api sends as a string of JSON, `null` and an
+/// empty string being the default.
+fn from_json_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + Default,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(json) if !json.is_empty() => {
+            serde_json::from_str(&json).map_err(serde::de::Error::custom)
+        }
+        _ => Ok(T::default()),
+    }
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -18,12 +72,85 @@ pub struct Data {
 
 impl QuestionContent {
     pub fn html_to_text(&self) -> String {
-        let string = self.content.as_bytes();
-        let s: String = from_read(string, string.len());
-        s.replace("\\n\\n", "\n\n")
-            .lines()
-            .filter(|l| !l.is_empty())
-            .collect::<Vec<&str>>()
-            .join("\n")
+        html_to_text(&self.content)
+    }
+
+    pub fn hints_to_text(&self) -> Vec<String> {
+        self.hints.iter().map(|h| html_to_text(h)).collect()
+    }
+
+    /// Summary lines: difficulty, votes, acceptance and topics.
+    pub fn details(&self) -> Vec<String> {
+        let mut summary = vec![];
+        if !self.difficulty.is_empty() {
+            summary.push(self.difficulty.clone());
+        }
+        summary.push(format!("👍 {} 👎 {}", self.likes, self.dislikes));
+        if let Some(stats) = &self.stats {
+            summary.push(format!(
+                "Acceptance {} ({}/{})",
+                stats.ac_rate, stats.total_accepted, stats.total_submission
+            ));
+        }
+        let mut lines = vec![summary.join(" · ")];
+        if !self.topic_tags.is_empty() {
+            let tags = self
+                .topic_tags
+                .iter()
+                .map(|t| t.name.as_str())
+                .collect::<Vec<_>>();
+            lines.push(format!("Topics: {}", tags.join(", ")));
+        }
+        lines
+    }
+}
+
+fn html_to_text(html: &str) -> String {
+    let string = html.as_bytes();
+    let s: String = from_read(string, string.len());
+    s.replace("\\n\\n", "\n\n")
+        .lines()
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Data;
+
+    #[test]
+    fn test_json_deserialization() {
+        let json = r#"{
+            "data": {
+                "question": {
+                    "content": "<p>Given an array</p>",
+                    "titleSlug": "two-sum",
+                    "hints": ["Use a <code>map</code>."],
+                    "difficulty": "Easy",
+                    "likes": 10,
+                    "dislikes": 2,
+                    "topicTags": [{"name": "Array", "id": "VG9w", "slug": "array"}],
+                    "similarQuestions": "[{\"title\": \"3Sum\", \"titleSlug\": \"3sum\", \"difficulty\": \"Medium\", \"translatedTitle\": null}]",
+                    "stats": "{\"totalAccepted\": \"1.2M\", \"totalSubmission\": \"2.4M\", \"totalAcceptedRaw\": 1200000, \"totalSubmissionRaw\": 2400000, \"acRate\": \"50.0%\"}"
+                }
+            }
+        }"#;
+
+        let question = serde_json::from_str::<Data>(json).unwrap().data.question;
+        assert_eq!(question.hints_to_text(), vec!["Use a `map`."]);
+        assert_eq!(question.similar_questions[0].title_slug, "3sum");
+        assert_eq!(
+            question.details(),
+            vec![
+                "Easy · 👍 10 👎 2 · Acceptance 50.0% (1.2M/2.4M)",
+                "Topics: Array"
+            ]
+        );
+
+        // older cached responses only have the content
+        let json = r#"{"data": {"question": {"content": "", "titleSlug": "a", "similarQuestions": null}}}"#;
+        let question = serde_json::from_str::<Data>(json).unwrap().data.question;
+        assert!(question.similar_questions.is_empty() && question.stats.is_none());
     }
 }
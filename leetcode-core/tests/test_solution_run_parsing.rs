@@ -47,7 +47,7 @@ fn test_run_correct_response_output() {
     let parsed_response = get_parsed_response("run_correct");
     assert_eq!(
         parsed_response.to_string(),
-        vec![
+        [
             "Solution ran successfully for 2/2 cases.",
             "Memory used: 2.00 MB",
             "Solution runtime: 0 ms",
@@ -61,7 +61,7 @@ fn test_run_wrong_response_output() {
     let parsed_response = get_parsed_response("run_wrong");
     assert_eq!(
         parsed_response.to_string(),
-        vec![
+        [
             "Test Run Failed: 0/3 cases passed.",
             "Memory used: 16.39 MB",
             "Solution runtime: 82 ms",
@@ -75,7 +75,7 @@ fn test_submit_correct_response_output() {
     let parsed_response = get_parsed_response("submit_correct");
     assert_eq!(
         parsed_response.to_string(),
-        vec![
+        [
             "Solution ran successfully for 57/57 cases.",
             "Memory used: 2.35 MB",
             "Solution runtime: 2 ms",
@@ -91,7 +91,7 @@ fn test_submit_wrong_response_output() {
     let parsed_response = get_parsed_response("submit_wrong");
     assert_eq!(
         parsed_response.to_string(),
-        vec![
+        [
             "3/80 cases passed.",
             "Memory used: 2.32 MB",
             "Solution runtime: N/A",
@@ -105,7 +105,7 @@ fn test_memory_limit_exceeded_response_output() {
     let parsed_response = get_parsed_response("memory_limit_exceeded");
     assert_eq!(
         parsed_response.to_string(),
-        vec!["Memory Limit Exceeded: 976.69 MB"].join("\n")
+        ["Memory Limit Exceeded: 976.69 MB"].join("\n")
     )
 }
 #[test]
@@ -113,7 +113,7 @@ fn test_output_limit_response_output() {
     let parsed_response = get_parsed_response("output_limit");
     assert_eq!(
         parsed_response.to_string(),
-        vec![
+        [
             r#"Output Limit Exceeded: Last Testcase: "maybe long testcase""#,
             r#"Expected Output:"true""#,
             r#"Std Output: "some_long_string""#,
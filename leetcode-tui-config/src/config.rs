@@ -1,9 +1,11 @@
+use super::keymap::Keymap;
+use super::layout::LayoutConfig;
 use super::theme::Theme;
 pub use crate::clients::{DB_CLIENT, REQ_CLIENT};
 use crate::utils::{get_config_dir, get_config_file_path};
 use color_eyre::Result;
-use serde::{Deserialize, Serialize};
 use leetcode_tui_shared::RoCell;
+use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::prelude::*;
 use std::{fs::create_dir_all, path::PathBuf};
@@ -41,32 +43,44 @@ pub struct Config {
     pub solutions_dir: PathBuf,
     #[serde(default)]
     pub theme: Theme,
+    #[serde(default)]
+    pub layout: LayoutConfig,
+    #[serde(default, skip_serializing)]
+    pub keymap: Keymap,
 }
 
 impl Config {
+    /// Path of the question database file.
+    pub fn db_path(&self) -> &str {
+        &self.db.path
+    }
+
     fn create_default_solution_dir() {
         create_dir_all(get_solutions_dir_path()).unwrap();
     }
 
     fn create_default_config(config_file: &PathBuf) {
-        let config_dir = config_file.as_path().parent().expect(&format!(
-            "Cannot get parent of the file path: {}",
-            config_file.display()
-        ));
-        create_dir_all(config_dir)
-            .expect(format!("Cannot create config directory @ {}", config_dir.display()).as_str());
+        let config_dir = config_file.as_path().parent().unwrap_or_else(|| {
+            panic!(
+                "Cannot get parent of the file path: {}",
+                config_file.display()
+            )
+        });
+        create_dir_all(config_dir).unwrap_or_else(|_| {
+            panic!("Cannot create config directory @ {}", config_dir.display())
+        });
         let default_config = Self::default();
         let default_config_str =
             toml::to_string(&default_config).expect("Cannot serialize default config to string.");
-        let mut file = File::create(&config_file)
-            .expect(format!("Cannot create file @ {}", config_file.display()).as_str());
-        file.write_all(default_config_str.as_bytes()).expect(
-            format!(
-                "Cannot write to the config file @ {}",
-                config_file.display()
-            )
-            .as_str(),
-        );
+        let mut file = File::create(config_file)
+            .unwrap_or_else(|_| panic!("Cannot create file @ {}", config_file.display()));
+        file.write_all(default_config_str.as_bytes())
+            .unwrap_or_else(|_| {
+                panic!(
+                    "Cannot write to the config file @ {}",
+                    config_file.display()
+                )
+            });
     }
 }
 
@@ -1,5 +1,7 @@
 use crossterm::event::{self, KeyEvent, KeyEventState, KeyModifiers};
+use serde::{Deserialize, Deserializer};
 use std::fmt;
+use std::str::FromStr;
 
 /// Represents an key.
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
@@ -123,6 +125,118 @@ impl fmt::Display for Key {
     }
 }
 
+/// Parses the notation written by `Display`: `j`, `<Space>`, `<Ctrl+s>`,
+/// `<Alt+x>`, `<Enter>`, `F5`, and the arrows `←` `→` `↑` `↓` (or `<Left>`,
+/// `<Right>`, `<Up>`, `<Down>`).
+impl FromStr for Key {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Ok(match c {
+                '←' => Key::Left,
+                '→' => Key::Right,
+                '↑' => Key::Up,
+                '↓' => Key::Down,
+                c => Key::Char(c),
+            });
+        }
+        if let Some(n) = s.strip_prefix('F').and_then(|n| n.parse::<u8>().ok()) {
+            if n <= 12 {
+                return Ok(Key::from_f(n));
+            }
+        }
+        let invalid = || format!("invalid key: {s}");
+        let inner = s
+            .strip_prefix('<')
+            .and_then(|s| s.strip_suffix('>'))
+            .ok_or_else(invalid)?;
+        let single = |c: &str| -> Result<char, String> {
+            if c == "Space" {
+                return Ok(' ');
+            }
+            let mut chars = c.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(c),
+                _ => Err(invalid()),
+            }
+        };
+        if let Some(c) = inner.strip_prefix("Ctrl+") {
+            return Ok(Key::Ctrl(single(c)?));
+        }
+        if let Some(c) = inner.strip_prefix("Alt+") {
+            return Ok(Key::Alt(single(c)?));
+        }
+        Ok(match inner {
+            "Space" => Key::Char(' '),
+            "Enter" => Key::Enter,
+            "Tab" => Key::Tab,
+            "Backspace" => Key::Backspace,
+            "Esc" => Key::Esc,
+            "Ins" => Key::Ins,
+            "Delete" => Key::Delete,
+            "Home" => Key::Home,
+            "End" => Key::End,
+            "PageUp" => Key::PageUp,
+            "PageDown" => Key::PageDown,
+            "Left" => Key::Left,
+            "Right" => Key::Right,
+            "Up" => Key::Up,
+            "Down" => Key::Down,
+            _ => return Err(invalid()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Keys pressed one after the other, written as their notations separated
+/// by spaces: `g g`, `<Ctrl+w> j`. A single key is a sequence of one.
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct KeySeq(pub Vec<Key>);
+
+impl fmt::Display for KeySeq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let keys: Vec<String> = self.0.iter().map(Key::to_string).collect();
+        write!(f, "{}", keys.join(" "))
+    }
+}
+
+impl FromStr for KeySeq {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let keys = s
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<Key>, _>>()?;
+        if keys.is_empty() {
+            // a lone space is the space key
+            return Ok(Self(vec![s.parse()?]));
+        }
+        Ok(Self(keys))
+    }
+}
+
+impl From<Key> for KeySeq {
+    fn from(key: Key) -> Self {
+        Self(vec![key])
+    }
+}
+
+impl<'de> Deserialize<'de> for KeySeq {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 impl From<Key> for KeyEvent {
     fn from(value: Key) -> Self {
         match value {
@@ -228,3 +342,39 @@ impl From<event::KeyEvent> for Key {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_what_it_displays() {
+        let keys = [
+            Key::Char('j'),
+            Key::Char('<'),
+            Key::Char(' '),
+            Key::Ctrl('s'),
+            Key::Alt(' '),
+            Key::Enter,
+            Key::PageDown,
+            Key::Up,
+            Key::F5,
+        ];
+        for key in keys {
+            assert_eq!(key.to_string().parse::<Key>(), Ok(key));
+        }
+        assert_eq!("<Left>".parse::<Key>(), Ok(Key::Left));
+        assert!("<Ctrl+ss>".parse::<Key>().is_err());
+        assert!("Enter".parse::<Key>().is_err());
+    }
+
+    #[test]
+    fn test_parses_sequences() {
+        let seq = KeySeq(vec![Key::Char('g'), Key::Char('g')]);
+        assert_eq!(seq.to_string(), "g g");
+        assert_eq!("g g".parse::<KeySeq>(), Ok(seq));
+        assert_eq!(" ".parse::<KeySeq>(), Ok(Key::Char(' ').into()));
+        assert_eq!("<Ctrl+s>".parse::<KeySeq>(), Ok(Key::Ctrl('s').into()));
+        assert!("gg".parse::<KeySeq>().is_err());
+    }
+}
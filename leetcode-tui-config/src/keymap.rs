@@ -0,0 +1,500 @@
+use indexmap::IndexMap;
+
+use serde::Deserialize;
+
+use crate::key::{Key, KeySeq};
+
+/// What a key does. The generic actions (`up`, `down`, `confirm`, `cancel`)
+/// act on whatever the context they are bound in shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    /// Removes a default binding of the key.
+    #[serde(rename = "none")]
+    Unbound,
+    Quit,
+    ToggleHelp,
+    OpenConfig,
+    Up,
+    Down,
+    Confirm,
+    Cancel,
+    DeleteChar,
+    NextTopic,
+    PrevTopic,
+    ToggleTopicMark,
+    ToggleTopicSetOp,
+    ClearTopicMarks,
+    CycleTopicSort,
+    NextTopicGroup,
+    PrevTopicGroup,
+    RandomQuestion,
+    OpenEditor,
+    Run,
+    Submit,
+    History,
+    EditNote,
+    AddToList,
+    RemoveFromList,
+    ToggleStats,
+    Search,
+    CommandLine,
+    Complete,
+    JumpToQuestion,
+    First,
+    Last,
+    PageUp,
+    PageDown,
+    HalfPageUp,
+    HalfPageDown,
+    GrowTopics,
+    ShrinkTopics,
+    ToggleTopics,
+    ToggleMaximize,
+    TogglePreview,
+    RevealHint,
+    SimilarQuestions,
+    PreviewUp,
+    PreviewDown,
+    ShowEditorial,
+    CommunitySolutions,
+    SaveCode,
+    ShowMissing,
+}
+
+/// The part of the UI receiving the keys, each with its own [`Bindings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Context {
+    /// Topics and questions.
+    List,
+    Popup,
+    Select,
+    Input,
+    Help,
+    Stats,
+    /// Reader of solution articles.
+    Article,
+}
+
+impl std::fmt::Display for Context {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Context::List => write!(f, "Questions and Topics"),
+            Context::Popup => write!(f, "Popup"),
+            Context::Select => write!(f, "Selection Popup"),
+            Context::Input => write!(f, "Prompt"),
+            Context::Help => write!(f, "Help"),
+            Context::Stats => write!(f, "Topic Stats"),
+            Context::Article => write!(f, "Article"),
+        }
+    }
+}
+
+impl Context {
+    /// Help text of the action when bound in this context.
+    pub fn describe(self, action: Action) -> &'static str {
+        use Action::*;
+        use Context::*;
+        match (self, action) {
+            (List, Up) => "Move to Previous Question",
+            (List, Down) => "Move to Next Question",
+            (List, Confirm) => "Read Question",
+            (Popup, Up) => "Scroll Up",
+            (Popup, Down) => "Scroll Down",
+            (Popup, Confirm | Cancel) => "Close Popup",
+            (Select | Help, Up) => "Move to Previous Item",
+            (Select | Help, Down) => "Move to Next Item",
+            (Select, Confirm) => "Choose Item",
+            (Select, Cancel) => "Close Without Choosing",
+            (Input, Confirm) => "Submit",
+            (Input, Cancel) => "Close Prompt",
+            (Input, Up) => "Close Prompt and Move/Previous Command",
+            (Input, Down) => "Close Prompt and Move/Next Command",
+            (Help, Confirm) => "Run Selected Action",
+            (Help, Cancel) => "Clear Filter/Close Help",
+            (Help, Search) => "Filter Actions",
+            (Stats, Confirm | Cancel) => "Close Stats",
+            (Article, Up) => "Scroll Up",
+            (Article, Down) => "Scroll Down",
+            (Article, First) => "Go to the Top",
+            (Article, Last) => "Go to the Bottom",
+            (Article, Confirm | Cancel) => "Close Article",
+            (_, Up) => "Move Up",
+            (_, Down) => "Move Down",
+            (_, Cancel) => "Cancel",
+            (_, Unbound) => "Nothing",
+            (_, Quit) => "Quit",
+            (_, ToggleHelp) => "Show/Hide Help",
+            (_, OpenConfig) => "Open config file",
+            (_, DeleteChar) => "Delete Character",
+            (_, NextTopic) => "Move to Next Topic",
+            (_, PrevTopic) => "Move to Previous Topic",
+            (_, ToggleTopicMark) => "Mark/Unmark Topic",
+            (_, ToggleTopicSetOp) => "Toggle AND/OR for Marked Topics",
+            (_, ClearTopicMarks) => "Clear Marked Topics",
+            (_, CycleTopicSort) => "Sort Topics by Name/Size/Completion",
+            (_, NextTopicGroup) => "Move to Next Topic Group",
+            (_, PrevTopicGroup) => "Move to Previous Topic Group",
+            (_, RandomQuestion) => "Move to Random Question",
+            (_, OpenEditor) => "Open Editor",
+            (_, Run) => "Run Solution",
+            (_, Submit) => "Submit Solution",
+            (_, History) => "Show Run/Submit History",
+            (_, EditNote) => "Edit Question Note",
+            (_, AddToList) => "Add Question to a List",
+            (_, RemoveFromList) => "Remove Question from the Hovered List",
+            (_, ToggleStats) => "Show/Hide topic stats",
+            (_, Search) => "Search",
+            (_, CommandLine) => "Open Command Line",
+            (_, Complete) => "Complete Command",
+            (_, First) => "Move to First Question",
+            (_, Last) => "Move to Last Question",
+            (_, PageUp) => "Scroll a Page Up",
+            (_, PageDown) => "Scroll a Page Down",
+            (_, HalfPageUp) => "Scroll Half a Page Up",
+            (_, HalfPageDown) => "Scroll Half a Page Down",
+            (_, GrowTopics) => "Grow Topics Pane",
+            (_, ShrinkTopics) => "Shrink Topics Pane",
+            (_, ToggleTopics) => "Show/Hide Topics Pane",
+            (_, ToggleMaximize) => "Maximize/Restore Popup",
+            (_, TogglePreview) => "Show/Hide Description Preview",
+            (_, RevealHint) => "Reveal Next Hint in the Preview",
+            (_, SimilarQuestions) => "Show Similar Questions",
+            (_, PreviewUp) => "Scroll Preview Up",
+            (_, PreviewDown) => "Scroll Preview Down",
+            (_, ShowEditorial) => "Read Official Solution",
+            (_, CommunitySolutions) => "Browse Community Solutions",
+            (_, SaveCode) => "Save Code as a New Solution File",
+            (_, ShowMissing) => "List Questions of the Curated List Missing Locally",
+            (_, JumpToQuestion) => "Jump to Question by Id (123G), Last Without a Count",
+        }
+    }
+}
+
+/// What the keys typed so far do in a context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lookup {
+    Action(Action),
+    /// The keys start a longer sequence.
+    Prefix,
+    Unbound,
+}
+
+/// Key sequences of a context with their actions, in the order they were
+/// bound.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bindings(Vec<(KeySeq, Action)>);
+
+impl Bindings {
+    fn new(bindings: &[(&str, Action)]) -> Self {
+        Self(
+            bindings
+                .iter()
+                .map(|(key, action)| (key.parse().expect("valid default key"), *action))
+                .collect(),
+        )
+    }
+
+    /// The action bound to `key` on its own.
+    pub fn action(&self, key: Key) -> Option<Action> {
+        match self.lookup(&[key]) {
+            Lookup::Action(action) => Some(action),
+            _ => None,
+        }
+    }
+
+    /// The first keys bound to `action`, for hints naming the key.
+    pub fn keys(&self, action: Action) -> Option<&KeySeq> {
+        self.0.iter().find(|(_, a)| *a == action).map(|(keys, _)| keys)
+    }
+
+    pub fn lookup(&self, keys: &[Key]) -> Lookup {
+        let mut lookup = Lookup::Unbound;
+        for (seq, action) in self.0.iter() {
+            if seq.0 == keys {
+                return Lookup::Action(*action);
+            }
+            if seq.0.starts_with(keys) {
+                lookup = Lookup::Prefix;
+            }
+        }
+        lookup
+    }
+
+    /// Every bound action with its keys, in the order of its first binding.
+    pub fn actions(&self) -> Vec<(Action, Vec<KeySeq>)> {
+        let mut actions: Vec<(Action, Vec<KeySeq>)> = vec![];
+        for (keys, action) in self.0.iter() {
+            match actions.iter_mut().find(|(a, _)| a == action) {
+                Some((_, seqs)) => seqs.push(keys.clone()),
+                None => actions.push((*action, vec![keys.clone()])),
+            }
+        }
+        actions
+    }
+
+    /// Rebinds the keys of `overrides`, keeping the position of keys already
+    /// bound, appending new ones in config order and dropping the ones bound
+    /// to [`Action::Unbound`].
+    fn merge(&mut self, overrides: IndexMap<KeySeq, Action>) {
+        for (key, action) in overrides {
+            match self.0.iter_mut().find(|(k, _)| *k == key) {
+                Some(binding) => binding.1 = action,
+                None => self.0.push((key, action)),
+            }
+        }
+        self.0.retain(|(_, a)| *a != Action::Unbound);
+    }
+}
+
+/// The `[keymap]` config section. Each context table maps keys to actions on
+/// top of the defaults, e.g.
+///
+/// ```toml
+/// [keymap.list]
+/// "<Ctrl+n>" = "down"
+/// "q" = "none"
+/// "g t" = "next_topic"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "KeymapConfig")]
+pub struct Keymap {
+    pub list: Bindings,
+    pub popup: Bindings,
+    pub select: Bindings,
+    pub input: Bindings,
+    pub help: Bindings,
+    pub stats: Bindings,
+    pub article: Bindings,
+}
+
+impl Keymap {
+    pub fn bindings(&self, context: Context) -> &Bindings {
+        match context {
+            Context::List => &self.list,
+            Context::Popup => &self.popup,
+            Context::Select => &self.select,
+            Context::Input => &self.input,
+            Context::Help => &self.help,
+            Context::Stats => &self.stats,
+            Context::Article => &self.article,
+        }
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        use Action::*;
+        let topic = [
+            ("t", NextTopic),
+            ("T", PrevTopic),
+            ("m", ToggleTopicMark),
+            ("M", ToggleTopicSetOp),
+            ("u", ClearTopicMarks),
+            ("o", CycleTopicSort),
+            ("]", NextTopicGroup),
+            ("[", PrevTopicGroup),
+            ("i", ShowMissing),
+        ];
+        let global = [("c", OpenConfig), ("?", ToggleHelp)];
+        let mut list = topic.to_vec();
+        list.extend([
+            ("<Ctrl+s>", ToggleStats),
+            ("j", Down),
+            ("↓", Down),
+            ("k", Up),
+            ("↑", Up),
+            ("r", RandomQuestion),
+            ("G", JumpToQuestion),
+            ("g g", First),
+            ("<Home>", First),
+            ("<End>", Last),
+            ("<PageDown>", PageDown),
+            ("<Ctrl+f>", PageDown),
+            ("<PageUp>", PageUp),
+            ("<Ctrl+b>", PageUp),
+            ("<Ctrl+d>", HalfPageDown),
+            ("<Ctrl+u>", HalfPageUp),
+            ("<Enter>", Confirm),
+            ("e", OpenEditor),
+            ("R", Run),
+            ("s", Submit),
+            ("h", History),
+            ("n", EditNote),
+            ("a", AddToList),
+            ("x", RemoveFromList),
+            ("/", Search),
+            (":", CommandLine),
+            (">", GrowTopics),
+            ("<", ShrinkTopics),
+            ("<Ctrl+t>", ToggleTopics),
+            ("p", TogglePreview),
+            ("H", RevealHint),
+            ("S", SimilarQuestions),
+            ("<Ctrl+y>", PreviewUp),
+            ("<Ctrl+e>", PreviewDown),
+            ("E", ShowEditorial),
+            ("D", CommunitySolutions),
+            ("q", Quit),
+        ]);
+        list.extend(global);
+        let mut stats = topic.to_vec();
+        stats.extend([
+            ("<Ctrl+s>", ToggleStats),
+            ("<Esc>", Cancel),
+            ("<Enter>", Confirm),
+        ]);
+        stats.extend(global);
+        let mut scroll = vec![
+            ("<Enter>", Confirm),
+            ("<Esc>", Cancel),
+            ("k", Up),
+            ("↑", Up),
+            ("j", Down),
+            ("↓", Down),
+            ("z", ToggleMaximize),
+        ];
+        scroll.extend(global);
+        let mut article = vec![
+            ("j", Down),
+            ("↓", Down),
+            ("k", Up),
+            ("↑", Up),
+            ("<PageDown>", PageDown),
+            ("<Ctrl+f>", PageDown),
+            ("<PageUp>", PageUp),
+            ("<Ctrl+b>", PageUp),
+            ("<Ctrl+d>", HalfPageDown),
+            ("<Ctrl+u>", HalfPageUp),
+            ("g g", First),
+            ("<Home>", First),
+            ("G", Last),
+            ("<End>", Last),
+            ("z", ToggleMaximize),
+            ("w", SaveCode),
+            ("<Esc>", Cancel),
+            ("q", Cancel),
+        ];
+        article.extend(global);
+        Self {
+            list: Bindings::new(&list),
+            popup: Bindings::new(&scroll),
+            select: Bindings::new(&scroll),
+            input: Bindings::new(&[
+                ("<Esc>", Cancel),
+                ("<Enter>", Confirm),
+                ("<Backspace>", DeleteChar),
+                ("<Tab>", Complete),
+                ("↑", Up),
+                ("↓", Down),
+            ]),
+            help: Bindings::new(&[
+                ("j", Down),
+                ("↓", Down),
+                ("k", Up),
+                ("↑", Up),
+                ("?", ToggleHelp),
+                ("<Esc>", Cancel),
+                ("<Enter>", Confirm),
+                ("/", Search),
+                ("c", OpenConfig),
+            ]),
+            stats: Bindings::new(&stats),
+            article: Bindings::new(&article),
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct KeymapConfig {
+    list: IndexMap<KeySeq, Action>,
+    popup: IndexMap<KeySeq, Action>,
+    select: IndexMap<KeySeq, Action>,
+    input: IndexMap<KeySeq, Action>,
+    help: IndexMap<KeySeq, Action>,
+    stats: IndexMap<KeySeq, Action>,
+    article: IndexMap<KeySeq, Action>,
+}
+
+impl From<KeymapConfig> for Keymap {
+    fn from(config: KeymapConfig) -> Self {
+        let mut keymap = Self::default();
+        keymap.list.merge(config.list);
+        keymap.popup.merge(config.popup);
+        keymap.select.merge(config.select);
+        keymap.input.merge(config.input);
+        keymap.help.merge(config.help);
+        keymap.stats.merge(config.stats);
+        keymap.article.merge(config.article);
+        keymap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overrides_merge_into_the_defaults() {
+        let keymap: Keymap = toml::from_str(
+            r#"
+            [list]
+            "<Ctrl+n>" = "down"
+            "q" = "none"
+            "s" = "search"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(keymap.list.action(Key::Ctrl('n')), Some(Action::Down));
+        assert_eq!(keymap.list.action(Key::Char('j')), Some(Action::Down));
+        assert_eq!(keymap.list.action(Key::Char('q')), None);
+        assert_eq!(keymap.list.action(Key::Char('s')), Some(Action::Search));
+        assert_eq!(keymap.popup, Keymap::default().popup);
+    }
+
+    #[test]
+    fn test_new_bindings_keep_the_config_order() {
+        let keymap: Keymap = toml::from_str(
+            r#"
+            [list]
+            "z" = "show_editorial"
+            "<Ctrl+a>" = "show_editorial"
+            "y" = "show_editorial"
+            "#,
+        )
+        .unwrap();
+        let keys: Vec<String> = keymap
+            .list
+            .actions()
+            .into_iter()
+            .find(|(action, _)| *action == Action::ShowEditorial)
+            .map(|(_, keys)| keys.iter().map(|k| k.to_string()).collect())
+            .unwrap_or_default();
+        assert_eq!(keys, ["E", "z", "<Ctrl+a>", "y"]);
+    }
+
+    #[test]
+    fn test_sequences() {
+        let keymap: Keymap = toml::from_str("[list]\n\"g t\" = \"next_topic\"").unwrap();
+        let g = Key::Char('g');
+        assert_eq!(keymap.list.lookup(&[g]), Lookup::Prefix);
+        assert_eq!(keymap.list.lookup(&[g, g]), Lookup::Action(Action::First));
+        assert_eq!(
+            keymap.list.lookup(&[g, Key::Char('t')]),
+            Lookup::Action(Action::NextTopic)
+        );
+        assert_eq!(keymap.list.lookup(&[g, Key::Char('x')]), Lookup::Unbound);
+        assert_eq!(keymap.list.action(g), None);
+        assert_eq!(
+            keymap.list.keys(Action::NextTopic).map(|k| k.to_string()),
+            Some("t".into())
+        );
+    }
+
+    #[test]
+    fn test_rejects_unknown_keys_and_actions() {
+        assert!(toml::from_str::<Keymap>("[list]\n\"<Hyper+x>\" = \"quit\"").is_err());
+        assert!(toml::from_str::<Keymap>("[list]\n\"x\" = \"explode\"").is_err());
+    }
+}
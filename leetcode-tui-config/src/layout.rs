@@ -0,0 +1,80 @@
+use leetcode_tui_shared::layout::LayoutOptions;
+use serde::{Deserialize, Serialize};
+
+/// The `[layout]` config section.
+///
+/// ```toml
+/// [layout]
+/// topics_width = 25
+/// popup_size = 80
+/// show_topics = true
+/// show_preview = false
+/// preview_height = 50
+/// stack_below_width = 80
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct LayoutConfig {
+    /// Share of the width given to the Topics pane, in percent.
+    pub topics_width: u16,
+    /// Width and height of popups, in percent of the terminal.
+    pub popup_size: u16,
+    pub show_topics: bool,
+    /// Shows the description of the hovered question below the list.
+    pub show_preview: bool,
+    /// Share of the Questions pane height given to the preview, in percent.
+    pub preview_height: u16,
+    /// Terminals narrower than this many columns stack the Topics pane
+    /// above the Questions pane.
+    pub stack_below_width: u16,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        let options = LayoutOptions::default();
+        Self {
+            topics_width: options.topics_percent,
+            popup_size: options.popup_percent,
+            show_topics: options.show_topics,
+            show_preview: options.show_preview,
+            preview_height: options.preview_percent,
+            stack_below_width: options.stack_below,
+        }
+    }
+}
+
+impl From<&LayoutConfig> for LayoutOptions {
+    /// Out of range percentages are clamped.
+    fn from(value: &LayoutConfig) -> Self {
+        Self {
+            topics_percent: value.topics_width.clamp(
+                LayoutOptions::MIN_TOPICS_PERCENT,
+                LayoutOptions::MAX_TOPICS_PERCENT,
+            ),
+            popup_percent: value.popup_size.clamp(20, 100),
+            show_topics: value.show_topics,
+            show_preview: value.show_preview,
+            preview_percent: value.preview_height.clamp(20, 80),
+            popup_maximized: false,
+            stack_below: value.stack_below_width,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_section_and_clamping() {
+        let config: LayoutConfig = toml::from_str("topics_width = 90\npopup_size = 5").unwrap();
+        assert!(config.show_topics);
+        let options = LayoutOptions::from(&config);
+        assert_eq!(options.topics_percent, LayoutOptions::MAX_TOPICS_PERCENT);
+        assert_eq!(options.popup_percent, 20);
+        assert_eq!(
+            LayoutOptions::from(&LayoutConfig::default()),
+            LayoutOptions::default()
+        );
+    }
+}
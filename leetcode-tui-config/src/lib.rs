@@ -3,6 +3,8 @@ mod config;
 pub mod constants;
 pub mod error_handling;
 pub mod key;
+pub mod keymap;
+pub mod layout;
 pub mod theme;
 pub mod utils;
 
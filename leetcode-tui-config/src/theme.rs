@@ -31,6 +31,29 @@ pub struct Border {
     pub hovered: Style,
 }
 
+#[derive(Serialize, Debug, Deserialize)]
+pub struct Help {
+    pub normal: Style,
+    pub hovered: Style,
+    /// Header rows naming the context of the rows below them.
+    pub section: Style,
+    pub border: Style,
+}
+
+impl Default for Help {
+    fn default() -> Self {
+        toml::from_str(
+            r#"
+            normal = { fg = '#a9b1d6' }
+            hovered = { fg = '#c0caf5', bg = '#292e42', bold = true }
+            section = { fg = '#7dcfff', bold = true }
+            border = { fg = '#7dcfff', bold = true }
+        "#,
+        )
+        .unwrap()
+    }
+}
+
 #[derive(Serialize, Debug, Deserialize)]
 pub struct Defaults {
     pub bg_dark: Color,
@@ -51,6 +74,8 @@ pub struct Theme {
     pub question: Question,
     pub topic: Topic,
     pub border: Border,
+    #[serde(default)]
+    pub help: Help,
     pub defaults: Defaults,
 }
 
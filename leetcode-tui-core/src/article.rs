@@ -0,0 +1,316 @@
+use leetcode_core::types::language::Language;
+use leetcode_tui_shared::layout::Window;
+
+use crate::content::question::sol_dir::SOLUTION_FILE_MANAGER;
+use crate::utils::wrapped_height;
+use crate::{emit, SendError};
+
+/// A line of an article as drawn by the reader.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArticleLine {
+    Heading(String),
+    Text(String),
+    /// A line of a fenced code block, indentation kept.
+    Code(String),
+    /// Stands in for what cannot be drawn in a terminal, e.g. slideshows.
+    Note(String),
+}
+
+impl ArticleLine {
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Heading(text) | Self::Text(text) | Self::Code(text) | Self::Note(text) => text,
+        }
+    }
+}
+
+/// A fenced code block of an article.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// Info string of the fence, e.g. `python3`.
+    pub language: Option<String>,
+    pub code: String,
+}
+
+/// The question an article solves, naming the files its code is saved to.
+#[derive(Debug, Clone, Default)]
+pub struct ArticleSource {
+    pub question_id: u32,
+    pub title_slug: String,
+    /// Tells saved files apart from the own solutions, e.g. `official` or
+    /// the author of a community solution.
+    pub variant: String,
+    /// Link to the article, written atop the saved files.
+    pub url: String,
+}
+
+/// Scrollable reader for the markdown articles of leetcode, such as the
+/// official and community solutions.
+#[derive(Default)]
+pub struct Article {
+    pub visible: bool,
+    title: String,
+    lines: Vec<ArticleLine>,
+    code_blocks: Vec<CodeBlock>,
+    source: ArticleSource,
+    scroll: usize,
+}
+
+impl Article {
+    pub fn open(&mut self, title: String, markdown: &str, source: ArticleSource) {
+        let (lines, code_blocks) = parse_markdown(markdown);
+        *self = Self {
+            visible: true,
+            title,
+            lines,
+            code_blocks,
+            source,
+            scroll: 0,
+        };
+    }
+
+    pub fn close(&mut self) -> bool {
+        self.visible = false;
+        true
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn lines(&self) -> &[ArticleLine] {
+        &self.lines
+    }
+
+    pub fn code_blocks(&self) -> &[CodeBlock] {
+        &self.code_blocks
+    }
+
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    /// Rows the article takes once [wrapped](crate::utils::wrap) to `width`.
+    pub fn height(&self, width: u16) -> usize {
+        wrapped_height(self.lines.iter().map(ArticleLine::text), width)
+    }
+
+    /// Scrolls by `delta` rows of the reader, stopping at the top and once
+    /// the last row is at the bottom.
+    pub fn scroll_by(&mut self, delta: isize) -> bool {
+        let area = Window::default().root.popup.inner;
+        self.scroll_in(delta, area.width, area.height)
+    }
+
+    fn scroll_in(&mut self, delta: isize, width: u16, height: u16) -> bool {
+        let scroll = self
+            .scroll
+            .saturating_add_signed(delta)
+            .min(self.height(width).saturating_sub(height as usize));
+        let changed = scroll != self.scroll;
+        self.scroll = scroll;
+        changed
+    }
+
+    /// Code blocks in a language solutions can be written in.
+    fn solutions(&self) -> Vec<(Language, &CodeBlock)> {
+        self.code_blocks
+            .iter()
+            .filter_map(|block| {
+                let language = Language::from_code_fence(block.language.as_deref()?)?;
+                Some((language, block))
+            })
+            .collect()
+    }
+
+    /// Saves a code block as a new solution file of the question, asking
+    /// which one when there are several.
+    pub fn save_code(&self) -> bool {
+        let solutions: Vec<(Language, CodeBlock)> = self
+            .solutions()
+            .into_iter()
+            .map(|(language, block)| (language, block.clone()))
+            .collect();
+        if solutions.is_empty() {
+            emit!(Popup(vec![
+                "There is no code to save in this article.".into()
+            ]));
+            return false;
+        }
+        let source = self.source.clone();
+        tokio::spawn(async move {
+            let selected = if solutions.len() == 1 {
+                0
+            } else {
+                let labels = solutions
+                    .iter()
+                    .map(|(language, block)| {
+                        let first_line = block.code.lines().next().unwrap_or_default();
+                        format!("{:<12} {}", language.to_string(), first_line.trim())
+                    })
+                    .collect();
+                match emit!(SelectPopup("Save code", labels)).await {
+                    Some(selected) => selected,
+                    None => return,
+                }
+            };
+            let (language, block) = &solutions[selected];
+            let contents = format!(
+                "{}\n\n{}",
+                language.comment_text(&format!("Saved from {}", source.url)),
+                block.code
+            );
+            if let Ok(path) = SOLUTION_FILE_MANAGER
+                .get()
+                .unwrap()
+                .write()
+                .unwrap()
+                .create_variant_solution_file(
+                    source.question_id,
+                    &source.title_slug,
+                    language,
+                    &source.variant,
+                    &contents,
+                )
+                .emit_if_error()
+            {
+                emit!(Popup(vec![format!("Saved to {}", path.display())]));
+            }
+        });
+        false
+    }
+}
+
+/// Splits leetcode flavoured markdown into lines and code blocks. Headings,
+/// fences and emphasis markers are dropped, LaTeX delimiters removed, and
+/// playgrounds, slideshows and figures replaced by a note.
+pub fn parse_markdown(markdown: &str) -> (Vec<ArticleLine>, Vec<CodeBlock>) {
+    let mut lines = vec![];
+    let mut code_blocks = vec![];
+    let mut code: Option<CodeBlock> = None;
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if let Some(info) = trimmed.strip_prefix("```") {
+            match code.take() {
+                Some(block) => code_blocks.push(block),
+                None => {
+                    let language = info.split_whitespace().next().map(String::from);
+                    if let Some(language) = &language {
+                        lines.push(ArticleLine::Note(language.clone()));
+                    }
+                    code = Some(CodeBlock {
+                        language,
+                        code: String::new(),
+                    });
+                }
+            }
+            continue;
+        }
+        if let Some(block) = code.as_mut() {
+            let line = line.replace('\t', "    ");
+            block.code.push_str(&line);
+            block.code.push('\n');
+            lines.push(ArticleLine::Code(line));
+            continue;
+        }
+        let line = match trimmed {
+            "[TOC]" => continue,
+            "" | "---" => {
+                // runs of blank lines are drawn as one
+                let after_blank =
+                    matches!(lines.last(), Some(ArticleLine::Text(t)) if t.is_empty());
+                if lines.is_empty() || after_blank {
+                    continue;
+                }
+                ArticleLine::Text(String::new())
+            }
+            _ if trimmed.starts_with("<iframe") => {
+                let src = trimmed
+                    .split("src=\"")
+                    .nth(1)
+                    .and_then(|s| s.split('"').next())
+                    .unwrap_or_default();
+                ArticleLine::Note(format!("Code playground: {src}"))
+            }
+            _ if trimmed.starts_with("!?!") => {
+                ArticleLine::Note("Slideshow, see the article on leetcode.com".into())
+            }
+            _ if trimmed.starts_with("![") => {
+                let alt = trimmed[2..].split(']').next().unwrap_or_default();
+                ArticleLine::Note(format!("Figure: {alt}"))
+            }
+            _ if trimmed.starts_with('#') => {
+                ArticleLine::Heading(clean(trimmed.trim_start_matches('#').trim()))
+            }
+            _ => ArticleLine::Text(clean(line.trim_end())),
+        };
+        lines.push(line);
+    }
+    // an unclosed fence runs to the end of the article
+    code_blocks.extend(code);
+    (lines, code_blocks)
+}
+
+/// Drops emphasis markers and LaTeX delimiters of a line of text.
+fn clean(line: &str) -> String {
+    line.replace("**", "").replace("$$", "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_leetcode_markdown() {
+        let markdown = "[TOC]\n## Solution\n\n\n**Approach 1**: $$O(n)$$\n\n\
+            ```python3 [sol]\nclass Solution:\n\tpass\n```\n\
+            <iframe src=\"https://leetcode.com/playground/abc/shared\" frameBorder=\"0\"></iframe>\n\
+            !?!../Documents/1_1.json:960,540!?!\n![Figure 1](../Figures/1.png)";
+        let (lines, code_blocks) = parse_markdown(markdown);
+        assert_eq!(
+            lines,
+            vec![
+                ArticleLine::Heading("Solution".into()),
+                ArticleLine::Text(String::new()),
+                ArticleLine::Text("Approach 1: O(n)".into()),
+                ArticleLine::Text(String::new()),
+                ArticleLine::Note("python3".into()),
+                ArticleLine::Code("class Solution:".into()),
+                ArticleLine::Code("    pass".into()),
+                ArticleLine::Note(
+                    "Code playground: https://leetcode.com/playground/abc/shared".into()
+                ),
+                ArticleLine::Note("Slideshow, see the article on leetcode.com".into()),
+                ArticleLine::Note("Figure: Figure 1".into()),
+            ]
+        );
+        assert_eq!(
+            code_blocks,
+            vec![CodeBlock {
+                language: Some("python3".into()),
+                code: "class Solution:\n    pass\n".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_scrolls_within_the_article() {
+        let mut article = Article::default();
+        article.open("Two Sum".into(), "a\nb\nc d", ArticleSource::default());
+        assert!(!article.scroll_in(-1, 2, 2));
+        // the last line wraps to two rows
+        assert_eq!(article.height(2), 4);
+        assert!(article.scroll_in(10, 2, 2));
+        assert_eq!(article.scroll(), 2);
+        assert!(!article.scroll_in(1, 2, 2));
+    }
+
+    #[test]
+    fn test_lists_code_blocks_in_solution_languages() {
+        let mut article = Article::default();
+        let markdown = "```Python3 []\npass\n```\n```\nplain\n```\n```C++\n;\n```";
+        article.open("Two Sum".into(), markdown, ArticleSource::default());
+        let languages: Vec<Language> = article.solutions().into_iter().map(|(l, _)| l).collect();
+        assert_eq!(languages, vec![Language::Python3, Language::Cpp]);
+    }
+}
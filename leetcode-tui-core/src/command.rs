@@ -0,0 +1,439 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use leetcode_core::types::language::Language;
+use leetcode_tui_config::DB_CLIENT;
+use leetcode_tui_db::QuestionQuery;
+
+use crate::content::question::QuestionSort;
+use crate::errors::{CoreError, CoreResult};
+use crate::SendError;
+
+/// Entries kept in the history file, older ones are dropped.
+const HISTORY_LEN: usize = 100;
+
+pub(crate) const SORT_USAGE: &str = "sort default|id|title|difficulty|acceptance|status";
+
+/// What an argument of a command is completed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arg {
+    /// Title slug of a question in the database.
+    Question,
+    Language,
+    Sort,
+    /// Slug of a topic of the Topics pane.
+    Topic,
+    Path,
+    ExportFormat,
+}
+
+/// An entry of the command registry.
+pub struct CommandSpec {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub description: &'static str,
+    args: &'static [Arg],
+}
+
+pub const COMMANDS: [CommandSpec; 8] = [
+    CommandSpec {
+        name: "open",
+        usage: "open <id|slug>",
+        description: "Open a question in the editor",
+        args: &[Arg::Question],
+    },
+    CommandSpec {
+        name: "goto",
+        usage: "goto <id|slug>",
+        description: "Hover a question, switching to the all topic if needed",
+        args: &[Arg::Question],
+    },
+    CommandSpec {
+        name: "lang",
+        usage: "lang [language]",
+        description: "Open the editor in this language, ask each time when unset",
+        args: &[Arg::Language],
+    },
+    CommandSpec {
+        name: "sort",
+        usage: SORT_USAGE,
+        description: "Sort the question list",
+        args: &[Arg::Sort],
+    },
+    CommandSpec {
+        name: "topic",
+        usage: "topic <slug>",
+        description: "Show the questions of a topic",
+        args: &[Arg::Topic],
+    },
+    CommandSpec {
+        name: "sync",
+        usage: "sync",
+        description: "Download the question list again",
+        args: &[],
+    },
+    CommandSpec {
+        name: "export",
+        usage: "export <path> [json|csv]",
+        description: "Back up the database to a json file or a csv directory",
+        args: &[Arg::Path, Arg::ExportFormat],
+    },
+    CommandSpec {
+        name: "quit",
+        usage: "quit",
+        description: "Quit",
+        args: &[],
+    },
+];
+
+/// The command with the given name, or the only one it is a prefix of.
+fn spec(name: &str) -> CoreResult<&'static CommandSpec> {
+    if let Some(spec) = COMMANDS.iter().find(|c| c.name == name) {
+        return Ok(spec);
+    }
+    let matches: Vec<_> = COMMANDS
+        .iter()
+        .filter(|c| !name.is_empty() && c.name.starts_with(name))
+        .collect();
+    match matches.as_slice() {
+        [spec] => Ok(spec),
+        _ => Err(CoreError::UnknownCommand(name.into())),
+    }
+}
+
+fn languages() -> impl Iterator<Item = Language> {
+    Language::ALL.into_iter()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+/// A parsed command line, e.g. `open 1143` or `sort acceptance`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Opens a question, given by id or title slug, in the editor.
+    Open(String),
+    /// Hovers a question given by id or title slug.
+    Goto(String),
+    /// Sets the editor language, `None` asks each time.
+    Lang(Option<Language>),
+    Sort(QuestionSort),
+    Topic(String),
+    Sync,
+    Export(PathBuf, ExportFormat),
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = CoreError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut words = line.split_whitespace();
+        let spec = spec(words.next().unwrap_or_default())?;
+        let args: Vec<&str> = words.collect();
+        let usage = || CoreError::CommandUsage(spec.usage);
+        match (spec.name, args.as_slice()) {
+            ("open", [question]) => Ok(Command::Open(question.to_string())),
+            ("goto", [question]) => Ok(Command::Goto(question.to_string())),
+            ("lang", []) => Ok(Command::Lang(None)),
+            ("lang", [name]) => languages()
+                .find(|l| l.to_string() == name.to_lowercase())
+                .map(|l| Command::Lang(Some(l)))
+                .ok_or_else(usage),
+            ("sort", [key]) => Ok(Command::Sort(key.parse()?)),
+            ("topic", [slug]) => Ok(Command::Topic(slug.to_string())),
+            ("sync", []) => Ok(Command::Sync),
+            ("export", [path]) => Ok(Command::Export(path.into(), ExportFormat::Json)),
+            ("export", [path, format]) => match format.to_lowercase().as_str() {
+                "json" => Ok(Command::Export(path.into(), ExportFormat::Json)),
+                "csv" => Ok(Command::Export(path.into(), ExportFormat::Csv)),
+                _ => Err(usage()),
+            },
+            ("quit", []) => Ok(Command::Quit),
+            _ => Err(usage()),
+        }
+    }
+}
+
+/// Result of completing the last word of a command line.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Completion {
+    /// The line with the word completed as far as the candidates agree.
+    pub line: String,
+    /// The candidates, when there is more than one.
+    pub candidates: Vec<String>,
+}
+
+/// Completes the last word of `line`, a command name or an argument.
+/// Question and topic slugs come from `slugs`.
+pub fn complete(line: &str, slugs: impl FnOnce(Arg) -> Vec<String>) -> Completion {
+    let (head, word) = line.split_at(line.rfind(' ').map_or(0, |i| i + 1));
+    let mut words = head.split_whitespace();
+    let candidates = match words.next() {
+        None => COMMANDS.iter().map(|c| c.name.to_string()).collect(),
+        Some(name) => match spec(name).ok().and_then(|s| s.args.get(words.count())) {
+            Some(Arg::Language) => languages().map(|l| l.to_string()).collect(),
+            Some(Arg::Sort) => QuestionSort::ALL.iter().map(|s| s.to_string()).collect(),
+            Some(Arg::ExportFormat) => vec!["json".into(), "csv".into()],
+            Some(Arg::Path) => paths(word),
+            Some(arg @ (Arg::Question | Arg::Topic)) => slugs(*arg),
+            None => vec![],
+        },
+    };
+    let mut candidates: Vec<String> = candidates
+        .into_iter()
+        .filter(|c| c.starts_with(word))
+        .collect();
+    candidates.sort();
+    candidates.dedup();
+    let completed = match candidates.as_slice() {
+        [] => word.to_string(),
+        // a finished word, unless it is a directory to complete further
+        [only] if only.ends_with('/') => only.clone(),
+        [only] => format!("{only} "),
+        [first, rest @ ..] => rest.iter().fold(first.clone(), |prefix, c| {
+            let len = prefix
+                .char_indices()
+                .zip(c.chars())
+                .take_while(|((_, a), b)| a == b)
+                .last()
+                .map_or(0, |((i, a), _)| i + a.len_utf8());
+            prefix[..len].to_string()
+        }),
+    };
+    if candidates.len() == 1 {
+        candidates.clear();
+    }
+    Completion {
+        line: format!("{head}{completed}"),
+        candidates,
+    }
+}
+
+/// Entries of the directory `word` points into, directories ending with `/`.
+fn paths(word: &str) -> Vec<String> {
+    let (dir, _) = word.split_at(word.rfind('/').map_or(0, |i| i + 1));
+    let Ok(entries) = std::fs::read_dir(if dir.is_empty() { "." } else { dir }) else {
+        return vec![];
+    };
+    entries
+        .filter_map(Result::ok)
+        .map(|e| {
+            let mut path = format!("{dir}{}", e.file_name().to_string_lossy());
+            if e.path().is_dir() {
+                path.push('/');
+            }
+            path
+        })
+        .collect()
+}
+
+/// State of the `:` command line kept between uses: the history, saved to a
+/// file, and the question slugs offered for completion.
+#[derive(Default)]
+pub struct CommandLine {
+    history: Vec<String>,
+    cursor: Option<usize>,
+    path: Option<PathBuf>,
+    slugs: Option<Vec<String>>,
+}
+
+impl CommandLine {
+    /// Loads the history from `path`, a missing file is an empty history.
+    pub fn load(path: &Path) -> Self {
+        let history = std::fs::read_to_string(path)
+            .map(|s| {
+                s.lines()
+                    .filter(|l| !l.trim().is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            history,
+            path: Some(path.to_path_buf()),
+            ..Default::default()
+        }
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Starts browsing the history again from the newest entry.
+    pub fn reset_cursor(&mut self) {
+        self.cursor = None;
+    }
+
+    /// Whether an entry of the history is shown.
+    pub fn is_browsing(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// The next older entry, staying on the oldest one.
+    pub fn older(&mut self) -> Option<&str> {
+        let i = match self.cursor {
+            Some(i) => i.saturating_sub(1),
+            None => self.history.len().checked_sub(1)?,
+        };
+        self.cursor = Some(i);
+        self.history.get(i).map(String::as_str)
+    }
+
+    /// The next newer entry, `None` past the newest one.
+    pub fn newer(&mut self) -> Option<&str> {
+        let i = self.cursor? + 1;
+        self.cursor = (i < self.history.len()).then_some(i);
+        self.history.get(i).map(String::as_str)
+    }
+
+    /// Adds `line` as the newest entry and writes the history file.
+    pub fn push(&mut self, line: &str) -> CoreResult<()> {
+        self.cursor = None;
+        self.history.retain(|l| l != line);
+        self.history.push(line.into());
+        if self.history.len() > HISTORY_LEN {
+            self.history.drain(..self.history.len() - HISTORY_LEN);
+        }
+        if let Some(path) = self.path.as_ref() {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, self.history.join("\n") + "\n")?;
+        }
+        Ok(())
+    }
+
+    /// Forgets the question slugs loaded for completion, e.g. after a sync.
+    pub fn invalidate(&mut self) {
+        self.slugs = None;
+    }
+
+    /// Completes `line` with the questions of the database and `topics`.
+    pub fn complete(&mut self, line: &str, topics: Vec<String>) -> Completion {
+        let slugs = &mut self.slugs;
+        complete(line, |arg| match arg {
+            Arg::Topic => topics,
+            _ => slugs
+                .get_or_insert_with(|| {
+                    QuestionQuery::new()
+                        .fetch(DB_CLIENT.as_ref())
+                        .emit_if_error()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|q| q.title_slug)
+                        .collect()
+                })
+                .clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_slugs(_: Arg) -> Vec<String> {
+        vec![]
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "open 1143".parse::<Command>().unwrap(),
+            Command::Open("1143".into())
+        );
+        assert_eq!(
+            "lang Rust".parse::<Command>().unwrap(),
+            Command::Lang(Some(Language::Rust))
+        );
+        assert_eq!("lang".parse::<Command>().unwrap(), Command::Lang(None));
+        assert_eq!(
+            "sort acceptance".parse::<Command>().unwrap(),
+            Command::Sort(QuestionSort::Acceptance)
+        );
+        assert_eq!(
+            "export out csv".parse::<Command>().unwrap(),
+            Command::Export("out".into(), ExportFormat::Csv)
+        );
+        assert_eq!(
+            "goto two-sum".parse::<Command>().unwrap(),
+            Command::Goto("two-sum".into())
+        );
+        // unique prefixes name a command
+        assert_eq!("q".parse::<Command>().unwrap(), Command::Quit);
+        assert_eq!("sy".parse::<Command>().unwrap(), Command::Sync);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            "s".parse::<Command>(),
+            Err(CoreError::UnknownCommand(_))
+        ));
+        assert!(matches!(
+            "frobnicate".parse::<Command>(),
+            Err(CoreError::UnknownCommand(_))
+        ));
+        assert!(matches!(
+            "open".parse::<Command>(),
+            Err(CoreError::CommandUsage("open <id|slug>"))
+        ));
+        assert!(matches!(
+            "sort likes".parse::<Command>(),
+            Err(CoreError::CommandUsage(SORT_USAGE))
+        ));
+        assert!("lang klingon".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn test_complete() {
+        assert_eq!(
+            complete("o", no_slugs),
+            Completion {
+                line: "open ".into(),
+                candidates: vec![]
+            }
+        );
+        assert_eq!(
+            complete("s", no_slugs),
+            Completion {
+                line: "s".into(),
+                candidates: vec!["sort".into(), "sync".into()]
+            }
+        );
+        assert_eq!(complete("sort ac", no_slugs).line, "sort acceptance ");
+        assert_eq!(complete("lang pyth", no_slugs).line, "lang python");
+        assert_eq!(complete("export out c", no_slugs).line, "export out csv ");
+        let slugs = |arg| {
+            assert_eq!(arg, Arg::Question);
+            vec!["two-sum".into(), "two-sum-ii".into(), "3sum".into()]
+        };
+        assert_eq!(
+            complete("open tw", slugs),
+            Completion {
+                line: "open two-sum".into(),
+                candidates: vec!["two-sum".into(), "two-sum-ii".into()]
+            }
+        );
+        assert_eq!(complete("quit x", no_slugs).line, "quit x");
+    }
+
+    #[test]
+    fn test_history() {
+        let mut command_line = CommandLine::default();
+        assert_eq!(command_line.older(), None);
+        for line in ["sync", "open 1", "sync"] {
+            command_line.push(line).unwrap();
+        }
+        assert_eq!(command_line.history(), ["open 1", "sync"]);
+        assert_eq!(command_line.older(), Some("sync"));
+        assert_eq!(command_line.older(), Some("open 1"));
+        assert_eq!(command_line.older(), Some("open 1"));
+        assert_eq!(command_line.newer(), Some("sync"));
+        assert_eq!(command_line.newer(), None);
+    }
+}
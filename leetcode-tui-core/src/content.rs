@@ -1,5 +1,8 @@
 pub mod question;
+use crate::errors::CoreResult;
+use crate::{emit, SendError};
 use leetcode_tui_config::DB_CLIENT;
+use leetcode_tui_db::DbList;
 use question::Questions;
 use topic::Topic;
 pub mod topic;
@@ -42,4 +45,52 @@ impl MainContent {
     pub fn get_questions(&self) -> &Questions {
         &self.questions
     }
+
+    /// Keeps the hovered topic and question visible in the resized panes.
+    pub fn resize(&mut self) {
+        self.topic.resize();
+        self.questions.resize();
+    }
+}
+
+impl MainContent {
+    /// Hovers the question with the given frontend id or title slug. When it
+    /// is not in the list the search is cleared, and if that is not enough
+    /// the `all` topic is shown.
+    pub fn jump_to_question(&mut self, id_or_slug: &str) -> CoreResult<bool> {
+        let question = question::find_question(id_or_slug)?;
+        if self.questions.jump_to_question(question.id) {
+            return Ok(true);
+        }
+        self.questions.filter_by(None)?;
+        if self.questions.jump_to_question(question.id) {
+            return Ok(true);
+        }
+        self.questions.jump_after_load(question.id);
+        self.topic.show_all()
+    }
+
+    /// Removes the hovered question from the list hovered in the topic pane.
+    pub fn remove_from_list(&mut self) -> bool {
+        let Some(name) = self
+            .topic
+            .hovered()
+            .and_then(|t| t.list_name())
+            .map(String::from)
+        else {
+            emit!(Popup(vec![
+                "Hover a list in the Topics pane to remove questions from it.".into()
+            ]));
+            return false;
+        };
+        if let Some(question) = self.questions.hovered() {
+            if DbList::remove_question(&name, question.id, DB_CLIENT.as_ref())
+                .emit_if_error()
+                .is_ok()
+            {
+                emit!(QuestionUpdate);
+            }
+        }
+        false
+    }
 }
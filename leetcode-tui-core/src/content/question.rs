@@ -1,23 +1,36 @@
-pub(super) mod sol_dir;
+mod search;
+pub(crate) mod sol_dir;
+mod sort;
 mod stats;
 
+use crate::article::ArticleSource;
+use crate::errors::{CoreError, CoreResult};
 use crate::SendError;
 use crate::{emit, utils::Paginate};
-use leetcode_tui_config::log;
-use leetcode_tui_config::DB_CLIENT;
-use leetcode_tui_config::REQ_CLIENT;
 use fuzzy_matcher::skim::SkimMatcherV2;
-use fuzzy_matcher::FuzzyMatcher;
+use leetcode_core::graphql::query::community_solutions::PAGE_SIZE;
 use leetcode_core::graphql::query::RunOrSubmitCodeCheckResult;
+use leetcode_core::types::community_solutions::QuestionSolutions;
+use leetcode_core::types::language::Language;
 use leetcode_core::types::run_submit_response::display::CustomDisplay;
 use leetcode_core::types::run_submit_response::ParsedResponse;
 use leetcode_core::{
-    GQLLeetcodeRequest, QuestionContentRequest, RunCodeRequest, SubmitCodeRequest,
+    CommunitySolutionRequest, CommunitySolutionsRequest, GQLLeetcodeRequest,
+    OfficialSolutionRequest, QuestionContentRequest, RunCodeRequest, SubmitCodeRequest,
+};
+use leetcode_tui_config::log;
+use leetcode_tui_config::DB_CLIENT;
+use leetcode_tui_config::REQ_CLIENT;
+use leetcode_tui_db::{
+    DbList, DbNote, DbQuestion, DbReview, DbSubmission, DbTopic, Grade, QuestionQuery,
+    SubmissionKind, TopicSetOp,
 };
-use leetcode_tui_db::{DbQuestion, DbTopic};
 use leetcode_tui_shared::layout::Window;
+use ratatui::widgets::ScrollbarState;
+use search::SearchQuery;
 pub(crate) use sol_dir::init;
 use sol_dir::SOLUTION_FILE_MANAGER;
+pub use sort::QuestionSort;
 use stats::Stats;
 use std::rc::Rc;
 
@@ -25,8 +38,12 @@ pub struct Questions {
     paginate: Paginate<Rc<DbQuestion>>,
     ques_haystack: Vec<Rc<DbQuestion>>,
     needle: Option<String>,
+    query: Option<SearchQuery>,
     matcher: SkimMatcherV2,
     show_stats: bool,
+    sort: QuestionSort,
+    language: Option<Language>,
+    pending_jump: Option<u32>,
 }
 
 impl Default for Questions {
@@ -34,9 +51,13 @@ impl Default for Questions {
         Self {
             paginate: Paginate::new(vec![]),
             needle: Default::default(),
+            query: Default::default(),
             ques_haystack: vec![],
             matcher: Default::default(),
             show_stats: Default::default(),
+            sort: Default::default(),
+            language: None,
+            pending_jump: None,
         }
     }
 }
@@ -50,6 +71,48 @@ impl Questions {
         self.paginate.next_elem(self.widget_height())
     }
 
+    pub fn first_ques(&mut self) -> bool {
+        self.paginate.first_elem(self.widget_height())
+    }
+
+    pub fn last_ques(&mut self) -> bool {
+        self.paginate.last_elem(self.widget_height())
+    }
+
+    pub fn page_up(&mut self) -> bool {
+        self.paginate.page_up(self.widget_height())
+    }
+
+    pub fn page_down(&mut self) -> bool {
+        self.paginate.page_down(self.widget_height())
+    }
+
+    pub fn half_page_up(&mut self) -> bool {
+        self.paginate.half_page_up(self.widget_height())
+    }
+
+    pub fn half_page_down(&mut self) -> bool {
+        self.paginate.half_page_down(self.widget_height())
+    }
+
+    /// Clamps the scroll position to the height of the resized pane.
+    pub fn resize(&mut self) {
+        self.paginate.resize(self.widget_height())
+    }
+
+    /// Hovers the question shown at `row` of the pane.
+    pub fn hover_row(&mut self, row: usize) -> bool {
+        self.paginate.hover_row(row, self.widget_height())
+    }
+
+    pub fn scroll(&mut self, delta: isize) -> bool {
+        self.paginate.scroll(delta, self.widget_height())
+    }
+
+    pub fn scrollbar(&self) -> Option<ScrollbarState> {
+        self.paginate.scrollbar(self.widget_height())
+    }
+
     pub fn rand_ques(&mut self) -> bool {
         self.paginate.rand_elem(self.widget_height())
     }
@@ -79,6 +142,15 @@ impl Questions {
         });
     }
 
+    pub fn get_questions_by_topics(&mut self, topics: Vec<DbTopic>, op: TopicSetOp) {
+        tokio::spawn(async move {
+            let questions = DbTopic::fetch_questions_for_topics(&topics, op, DB_CLIENT.as_ref());
+            if let Ok(_questions) = questions.emit_if_error() {
+                emit!(Questions(_questions));
+            }
+        });
+    }
+
     pub fn show_question_content(&self) -> bool {
         if let Some(_hovered) = self.hovered() {
             let slug = _hovered.title_slug.clone();
@@ -86,13 +158,10 @@ impl Questions {
             tokio::spawn(async move {
                 let qc = QuestionContentRequest::new(slug);
                 if let Ok(content) = qc.send(REQ_CLIENT.as_ref()).await.emit_if_error() {
-                    let lines = content
-                        .data
-                        .question
-                        .html_to_text()
-                        .lines()
-                        .map(|l| l.to_string())
-                        .collect::<Vec<String>>();
+                    let question = content.data.question;
+                    let mut lines = question.details();
+                    lines.push(String::new());
+                    lines.extend(question.html_to_text().lines().map(|l| l.to_string()));
                     emit!(Popup(title, lines));
                 }
             });
@@ -114,18 +183,17 @@ impl Questions {
         if let Some(_hovered) = self.hovered() {
             let mut cloned_quest = _hovered.as_ref().clone();
             let id = _hovered.id.to_string();
-            if let Ok(lang_refs) = SOLUTION_FILE_MANAGER
+            if let Ok(labels) = SOLUTION_FILE_MANAGER
                 .get()
                 .unwrap()
                 .read()
                 .unwrap()
-                .get_available_languages(id.as_str())
+                .get_solution_labels(id.as_str())
                 .emit_if_error()
             {
-                let cloned_langs = lang_refs.iter().map(|v| v.to_string()).collect();
                 tokio::spawn(async move {
                     if let Some(selected_lang) =
-                        emit!(SelectPopup("Available solutions in", cloned_langs)).await
+                        emit!(SelectPopup("Available solutions in", labels)).await
                     {
                         let selected_sol_file = SOLUTION_FILE_MANAGER
                             .get()
@@ -137,6 +205,8 @@ impl Questions {
                         if let Ok(f) = selected_sol_file.emit_if_error() {
                             if let Ok(contents) = f.read_contents().await.emit_if_error() {
                                 let lang = f.language;
+                                let lang_name = lang.to_string();
+                                let code = contents.clone();
                                 let request = if is_submit {
                                     SubmitCodeRequest::new(
                                         lang,
@@ -170,6 +240,21 @@ impl Questions {
                                 };
 
                                 if let Ok(response) = request.emit_if_error() {
+                                    let kind = if is_submit {
+                                        SubmissionKind::Submit
+                                    } else {
+                                        SubmissionKind::Run
+                                    };
+                                    let _ = DbSubmission::new(
+                                        cloned_quest.id,
+                                        &lang_name,
+                                        kind,
+                                        &code,
+                                        &response,
+                                    )
+                                    .save_to_db(DB_CLIENT.as_ref())
+                                    .emit_if_error();
+
                                     if let Ok(update_result) = cloned_quest
                                         .mark_attempted(DB_CLIENT.as_ref())
                                         .emit_if_error()
@@ -181,23 +266,25 @@ impl Questions {
                                         }
                                     }
 
-                                    if is_submit {
-                                        let is_submission_accepted =
-                                            matches!(response, ParsedResponse::SubmitAccepted(..));
-                                        if is_submission_accepted {
-                                            if let Ok(update_result) = cloned_quest
-                                                .mark_accepted(DB_CLIENT.as_ref())
-                                                .emit_if_error()
-                                            {
-                                                // when solution is accepted
-                                                if update_result.is_some() {
-                                                    // fetches latest result from db
-                                                    emit!(QuestionUpdate);
-                                                }
-                                            };
-                                        }
+                                    let is_submission_accepted =
+                                        matches!(response, ParsedResponse::SubmitAccepted(..));
+                                    if is_submit && is_submission_accepted {
+                                        if let Ok(update_result) = cloned_quest
+                                            .mark_accepted(DB_CLIENT.as_ref())
+                                            .emit_if_error()
+                                        {
+                                            // when solution is accepted
+                                            if update_result.is_some() {
+                                                // fetches latest result from db
+                                                emit!(QuestionUpdate);
+                                            }
+                                        };
+                                        // the verdict first, then how well it was recalled
+                                        emit!(PopupWait(response.get_display_lines())).await;
+                                        schedule_review(cloned_quest.id).await;
+                                    } else {
+                                        emit!(Popup(response.get_display_lines()));
                                     }
-                                    emit!(Popup(response.get_display_lines()));
                                 }
                             }
                         }
@@ -208,55 +295,328 @@ impl Questions {
         false
     }
 
-    pub fn solve_for_language(&self) -> bool {
-        if let Some(_hovered) = self.hovered() {
-            let slug = _hovered.title_slug.clone();
+    /// Shows the locally recorded runs and submits of the hovered question.
+    pub fn show_submission_history(&self) -> bool {
+        if let Some(hovered) = self.hovered() {
+            if let Ok(submissions) =
+                DbSubmission::fetch_for_question(hovered.id, DB_CLIENT.as_ref()).emit_if_error()
+            {
+                let lines = if submissions.is_empty() {
+                    vec!["No runs or submissions recorded yet.".into()]
+                } else {
+                    let now = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.as_secs())
+                        .unwrap_or_default();
+                    submissions
+                        .iter()
+                        .map(|s| submission_line(s, now))
+                        .collect()
+                };
+                emit!(Popup(format!("History: {}", hovered.title), lines));
+            }
+        }
+        false
+    }
+
+    /// Opens the official solution of the hovered question in the article
+    /// reader.
+    pub fn show_editorial(&self) -> bool {
+        let Some(hovered) = self.hovered() else {
+            return false;
+        };
+        if !hovered.has_solution {
+            emit!(Popup(vec![format!(
+                "There is no official solution for {}.",
+                hovered.title
+            )]));
+            return false;
+        }
+        let slug = hovered.title_slug.clone();
+        let source = ArticleSource {
+            question_id: hovered.id,
+            url: format!("https://leetcode.com/problems/{slug}/editorial/"),
+            title_slug: slug.clone(),
+            variant: "official".into(),
+        };
+        tokio::spawn(async move {
+            if let Ok((title, markdown)) = OfficialSolutionRequest::new(slug)
+                .send(REQ_CLIENT.as_ref())
+                .await
+                .and_then(|solution| solution.into_article())
+                .emit_if_error()
+            {
+                emit!(Article(title, markdown, source));
+            }
+        });
+        false
+    }
+
+    /// Lists the most voted community solutions of the hovered question in a
+    /// language picked first, and opens the chosen one in the article
+    /// reader.
+    pub fn show_community_solutions(&self) -> bool {
+        let Some(hovered) = self.hovered() else {
+            return false;
+        };
+        let id = hovered.id;
+        let slug = hovered.title_slug.clone();
+        let title = hovered.title.clone();
+        tokio::spawn(async move {
+            let Ok(editor_data) = leetcode_core::EditorDataRequest::new(slug.clone())
+                .send(REQ_CLIENT.as_ref())
+                .await
+                .emit_if_error()
+            else {
+                return;
+            };
+            let languages: Vec<Language> =
+                editor_data.get_languages().into_iter().cloned().collect();
+            let mut names = vec!["All languages".to_string()];
+            names.extend(languages.iter().map(|l| l.to_string()));
+            let Some(selected) = emit!(SelectPopup("Community solutions in", names)).await else {
+                return;
+            };
+            let language_tag = selected.checked_sub(1).map(|i| languages[i].to_string());
+            let mut skip = 0;
+            let solution = loop {
+                let Ok(list) =
+                    CommunitySolutionsRequest::new(slug.clone(), language_tag.clone(), skip)
+                        .send(REQ_CLIENT.as_ref())
+                        .await
+                        .emit_if_error()
+                else {
+                    return;
+                };
+                let QuestionSolutions {
+                    total_num,
+                    mut solutions,
+                } = list.data.question_solutions;
+                if solutions.is_empty() && skip == 0 {
+                    emit!(Popup(vec![format!("No community solutions for {title}")]));
+                    return;
+                }
+                let mut lines: Vec<String> = solutions
+                    .iter()
+                    .map(|s| {
+                        format!(
+                            "▲{:>6}  {}  by {}  {}",
+                            s.post.vote_count,
+                            s.title,
+                            s.post.author(),
+                            s.tags()
+                        )
+                    })
+                    .collect();
+                // paging entries below the solutions
+                let shown = solutions.len();
+                let next = (skip + shown as u32) < total_num;
+                if next {
+                    lines.push("▸ Next page".into());
+                }
+                if skip > 0 {
+                    lines.push("◂ Previous page".into());
+                }
+                let popup_title = format!(
+                    "Community solutions: {title} ({}-{} of {total_num})",
+                    skip + 1,
+                    skip + shown as u32
+                );
+                let Some(selected) = emit!(SelectPopup(popup_title, lines)).await else {
+                    return;
+                };
+                match selected.checked_sub(shown) {
+                    None => break solutions.swap_remove(selected),
+                    Some(0) if next => skip += PAGE_SIZE,
+                    Some(_) => skip = skip.saturating_sub(PAGE_SIZE),
+                }
+            };
+            let source = ArticleSource {
+                question_id: id,
+                url: format!(
+                    "https://leetcode.com/problems/{slug}/solutions/{}/",
+                    solution.id
+                ),
+                title_slug: slug,
+                variant: solution.post.author().to_string(),
+            };
+            if let Ok((title, markdown)) = CommunitySolutionRequest::new(solution.id)
+                .send(REQ_CLIENT.as_ref())
+                .await
+                .and_then(|topic| topic.into_article())
+                .emit_if_error()
+            {
+                emit!(Article(title, markdown, source));
+            }
+        });
+        false
+    }
+
+    /// Lists the questions similar to the hovered one; the picked one is
+    /// hovered in the question list.
+    pub fn show_similar_questions(&self) -> bool {
+        if let Some(hovered) = self.hovered() {
+            let slug = hovered.title_slug.clone();
+            let title = hovered.title.clone();
             tokio::spawn(async move {
-                if let Ok(editor_data) = leetcode_core::EditorDataRequest::new(slug)
+                let Ok(content) = QuestionContentRequest::new(slug)
                     .send(REQ_CLIENT.as_ref())
                     .await
                     .emit_if_error()
+                else {
+                    return;
+                };
+                let mut similar = content.data.question.similar_questions;
+                if similar.is_empty() {
+                    emit!(Popup(vec![format!("No questions similar to {title}")]));
+                    return;
+                }
+                let lines = similar
+                    .iter()
+                    .map(|q| format!("{:<6}  {}", q.difficulty, q.title))
+                    .collect();
+                if let Some(selected) =
+                    emit!(SelectPopup(format!("Similar to {title}"), lines)).await
                 {
-                    if let Some(selected) = emit!(SelectPopup(
-                        "Select Language",
-                        editor_data
-                            .get_languages()
-                            .iter()
-                            .map(|l| l.to_string())
-                            .collect()
-                    ))
+                    emit!(Goto(similar.swap_remove(selected).title_slug));
+                }
+            });
+        }
+        false
+    }
+
+    /// Adds the hovered question to a list picked from the existing ones, or
+    /// to a new list named in a prompt.
+    pub fn add_to_list(&self) -> bool {
+        if let Some(hovered) = self.hovered() {
+            let id = hovered.id;
+            tokio::spawn(async move {
+                let Ok(lists) = DbList::fetch_all(DB_CLIENT.as_ref()).emit_if_error() else {
+                    return;
+                };
+                let mut names: Vec<String> = lists.into_iter().map(|l| l.name).collect();
+                names.push("+ New list".into());
+                let Some(selected) = emit!(SelectPopup("Add to list", names.clone())).await else {
+                    return;
+                };
+                let name = if selected == names.len() - 1 {
+                    match emit!(Prompt("List name: ")).await {
+                        Some(name) if !name.trim().is_empty() => name.trim().to_string(),
+                        _ => return,
+                    }
+                } else {
+                    names.swap_remove(selected)
+                };
+                if let Ok(added) =
+                    DbList::add_question(&name, id, DB_CLIENT.as_ref()).emit_if_error()
+                {
+                    if !added {
+                        emit!(Popup(vec![format!("Question {id} is already in {name}")]));
+                    }
+                    emit!(QuestionUpdate);
+                }
+            });
+        }
+        false
+    }
+
+    /// Opens the note of the hovered question in the editor through a temp
+    /// markdown file, saving it back once the editor is closed.
+    pub fn edit_note(&self) -> bool {
+        if let Some(hovered) = self.hovered() {
+            let id = hovered.id;
+            let prefix = format!("leetcode-{id}-{}-", hovered.title_slug);
+            let template = format!("# {id}. {}\n\n", hovered.title);
+            tokio::spawn(async move {
+                let existing = DbNote::get(id, DB_CLIENT.as_ref()).emit_if_error();
+                let Ok(existing) = existing else {
+                    return;
+                };
+                // created exclusively under a random name, removed once dropped
+                let Ok(file) = tempfile::Builder::new()
+                    .prefix(&prefix)
+                    .suffix(".md")
+                    .tempfile()
+                    .emit_if_error()
+                else {
+                    return;
+                };
+                let path = file.path().to_path_buf();
+                let initial = existing.map_or(template.clone(), |n| n.content);
+                if tokio::fs::write(&path, &initial)
                     .await
-                    {
-                        let selected_lang = editor_data.get_languages()[selected];
-                        let editor_content = editor_data.get_editor_data_by_language(selected_lang);
-                        if let Ok(file_name) =
-                            editor_data.get_filename(selected_lang).emit_if_error()
-                        {
-                            if let Some(e_data) = editor_content {
-                                if let Ok(written_path) = SOLUTION_FILE_MANAGER
-                                    .get()
-                                    .unwrap()
-                                    .write()
-                                    .unwrap()
-                                    .create_solution_file(file_name.as_str(), e_data)
-                                    .emit_if_error()
-                                {
-                                    emit!(Open(written_path));
-                                }
-                            };
-                        };
+                    .emit_if_error()
+                    .is_err()
+                {
+                    return;
+                }
+                emit!(OpenWait(path.clone())).await;
+                if let Ok(content) = tokio::fs::read_to_string(&path).await.emit_if_error() {
+                    // an untouched template is not worth keeping
+                    let content = if content.trim() == template.trim() {
+                        ""
                     } else {
-                        log::info!("quitting popup unselected");
+                        content.as_str()
+                    };
+                    if content != initial
+                        && DbNote::save(id, content, DB_CLIENT.as_ref())
+                            .emit_if_error()
+                            .is_ok()
+                    {
+                        emit!(QuestionUpdate);
                     }
                 }
+                drop(file);
             });
         }
         false
     }
 
+    pub fn solve_for_language(&self) -> bool {
+        if let Some(_hovered) = self.hovered() {
+            solve(_hovered.title_slug.clone(), self.language.clone());
+        }
+        false
+    }
+
+    /// Opens the question with the given id or title slug in the editor,
+    /// whether or not it is in the current list.
+    pub fn open(&self, id_or_slug: &str) -> CoreResult<()> {
+        let question = find_question(id_or_slug)?;
+        solve(question.title_slug, self.language.clone());
+        Ok(())
+    }
+
+    /// Hovers the question with the given id if it is in the list.
+    pub fn jump_to_question(&mut self, id: u32) -> bool {
+        let Some(position) = self.paginate.list().iter().position(|q| q.id == id) else {
+            return false;
+        };
+        self.paginate.jump_to(position, self.widget_height());
+        true
+    }
+
+    /// Hovers the question with the given id once the next list of
+    /// questions arrives.
+    pub(crate) fn jump_after_load(&mut self, id: u32) {
+        self.pending_jump = Some(id);
+    }
+
+    /// Language the editor is opened with, `None` asks every time.
+    pub fn set_language(&mut self, language: Option<Language>) {
+        self.language = language;
+    }
+
+    pub fn language(&self) -> Option<&Language> {
+        self.language.as_ref()
+    }
+
     pub fn set_questions(&mut self, questions: Vec<DbQuestion>) {
         self.ques_haystack = questions.into_iter().map(Rc::new).collect();
         self.filter_questions();
+        if let Some(id) = self.pending_jump.take() {
+            self.jump_to_question(id);
+        }
     }
 }
 
@@ -276,42 +636,41 @@ impl Questions {
         false
     }
 
-    pub fn filter_by(&mut self, string: Option<String>) {
+    /// Filters the question list with the search query typed in the `/` prompt.
+    /// On a parse error the current list is kept and the error is returned.
+    pub fn filter_by(&mut self, string: Option<String>) -> CoreResult<()> {
         if self.needle != string {
+            let query = string.as_deref().map(str::parse).transpose()?;
             self.needle = string;
+            self.query = query;
             self.filter_questions();
         }
+        Ok(())
     }
 
     fn filter_questions(&mut self) {
-        let fil_quests = if let Some(needle) = self.needle.as_ref() {
-            let quests: Vec<Rc<DbQuestion>> = self
-                .ques_haystack
+        let mut fil_quests: Vec<_> = if let Some(query) = self.query.as_ref() {
+            self.ques_haystack
                 .iter()
-                .filter(|q| {
-                    let search_string = format!(
-                        "{} {} {}", // id, topics, title
-                        q.id,
-                        q.topics
-                            .iter()
-                            .map(|t| t.slug.as_str())
-                            .collect::<Vec<&str>>()
-                            .join(", "),
-                        q.title
-                    );
-
-                    self.matcher
-                        .fuzzy_match(search_string.as_str(), &needle)
-                        .is_some()
-                })
+                .filter(|q| query.matches(q, &self.matcher))
                 .cloned()
-                .collect();
-            quests
+                .collect()
         } else {
             self.ques_haystack.clone()
         };
+        self.sort.sort(&mut fil_quests);
         self.paginate.update_list(fil_quests);
     }
+
+    pub fn set_sort(&mut self, sort: QuestionSort) -> bool {
+        self.sort = sort;
+        self.filter_questions();
+        true
+    }
+
+    pub fn sort(&self) -> QuestionSort {
+        self.sort
+    }
 }
 
 impl Questions {
@@ -328,3 +687,109 @@ impl Questions {
         self.show_stats
     }
 }
+
+/// The question with the given frontend id or title slug.
+pub(crate) fn find_question(id_or_slug: &str) -> CoreResult<DbQuestion> {
+    let query = match id_or_slug.parse::<u32>() {
+        Ok(id) => QuestionQuery::new().ids(id..=id),
+        Err(_) => {
+            let slug = id_or_slug.to_string();
+            QuestionQuery::new().filter(move |q| q.title_slug == slug)
+        }
+    };
+    query
+        .fetch(DB_CLIENT.as_ref())
+        .ok()
+        .and_then(|questions| questions.into_iter().next())
+        .ok_or_else(|| CoreError::QuestionNotFound(id_or_slug.into()))
+}
+
+/// Writes the starter code of the question to a solution file and opens it,
+/// asking for the language unless `language` is set and offered for it.
+fn solve(slug: String, language: Option<Language>) {
+    tokio::spawn(async move {
+        let Ok(editor_data) = leetcode_core::EditorDataRequest::new(slug)
+            .send(REQ_CLIENT.as_ref())
+            .await
+            .emit_if_error()
+        else {
+            return;
+        };
+        let languages = editor_data.get_languages();
+        let selected_lang = match language.and_then(|l| languages.iter().find(|x| **x == &l)) {
+            Some(lang) => *lang,
+            None => {
+                let Some(selected) = emit!(SelectPopup(
+                    "Select Language",
+                    languages.iter().map(|l| l.to_string()).collect()
+                ))
+                .await
+                else {
+                    log::info!("quitting popup unselected");
+                    return;
+                };
+                languages[selected]
+            }
+        };
+        let editor_content = editor_data.get_editor_data_by_language(selected_lang);
+        if let Ok(file_name) = editor_data.get_filename(selected_lang).emit_if_error() {
+            if let Some(e_data) = editor_content {
+                if let Ok(written_path) = SOLUTION_FILE_MANAGER
+                    .get()
+                    .unwrap()
+                    .write()
+                    .unwrap()
+                    .create_solution_file(file_name.as_str(), e_data)
+                    .emit_if_error()
+                {
+                    emit!(Open(written_path));
+                }
+            };
+        };
+    });
+}
+
+/// Asks how well an accepted question was recalled and schedules its next
+/// review accordingly.
+async fn schedule_review(question_id: u32) {
+    let grades = Grade::ALL.iter().map(|g| g.to_string()).collect();
+    if let Some(selected) =
+        emit!(SelectPopup("Accepted! How well did you recall it?", grades)).await
+    {
+        let today = leetcode_tui_db::models::review::today();
+        if DbReview::record(question_id, Grade::ALL[selected], today, DB_CLIENT.as_ref())
+            .emit_if_error()
+            .is_ok()
+        {
+            emit!(QuestionUpdate);
+        }
+    }
+}
+
+fn format_age(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{secs}s ago"),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86399 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86400),
+    }
+}
+
+fn submission_line(s: &DbSubmission, now: u64) -> String {
+    let with_percentile =
+        |value: &Option<String>, percentile: Option<f32>| match (value, percentile) {
+            (Some(v), Some(p)) => format!("{v} (beats {p:.1}%)"),
+            (Some(v), None) => v.clone(),
+            _ => "-".into(),
+        };
+    format!(
+        "{:>8}  {:<6} {:<22} {:<10} {}  {}  #{:08x}",
+        format_age(now.saturating_sub(s.timestamp)),
+        s.kind.to_string(),
+        s.verdict,
+        s.language,
+        with_percentile(&s.runtime, s.runtime_percentile),
+        with_percentile(&s.memory, s.memory_percentile),
+        s.code_hash >> 32,
+    )
+}
@@ -0,0 +1,235 @@
+use std::str::FromStr;
+
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use leetcode_tui_db::DbQuestion;
+
+use crate::errors::CoreError;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum StatusFilter {
+    Todo,
+    Attempted,
+    Solved,
+}
+
+impl StatusFilter {
+    fn matches(&self, status: Option<&str>) -> bool {
+        match self {
+            StatusFilter::Todo => status.is_none(),
+            StatusFilter::Attempted => status == Some("notac"),
+            StatusFilter::Solved => status == Some("ac"),
+        }
+    }
+}
+
+impl FromStr for StatusFilter {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "todo" | "new" => Ok(Self::Todo),
+            "attempted" | "notac" | "tried" => Ok(Self::Attempted),
+            "solved" | "ac" | "done" => Ok(Self::Solved),
+            _ => Err(CoreError::InvalidSearchValue("s".into(), s.into())),
+        }
+    }
+}
+
+/// Parsed form of the text typed in the `/` prompt.
+///
+/// Field filters (`d:hard`, `t:dynamic-programming`, `s:todo`, `#123`) and
+/// quoted phrases must all match; the remaining words are fuzzy matched
+/// against the question title.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct SearchQuery {
+    difficulty: Option<String>,
+    topics: Vec<String>,
+    status: Option<StatusFilter>,
+    id: Option<u32>,
+    phrases: Vec<String>,
+    fuzzy: Vec<String>,
+}
+
+fn parse_difficulty(value: &str) -> Result<String, CoreError> {
+    match value.to_lowercase().as_str() {
+        "e" | "easy" => Ok("Easy".into()),
+        "m" | "medium" => Ok("Medium".into()),
+        "h" | "hard" => Ok("Hard".into()),
+        _ => Err(CoreError::InvalidSearchValue("d".into(), value.into())),
+    }
+}
+
+impl FromStr for SearchQuery {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut query = Self::default();
+        let mut chars = s.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c.is_whitespace() {
+                continue;
+            }
+
+            if c == '"' {
+                let mut phrase = String::new();
+                let mut terminated = false;
+                for p in chars.by_ref() {
+                    if p == '"' {
+                        terminated = true;
+                        break;
+                    }
+                    phrase.push(p);
+                }
+                if !terminated {
+                    return Err(CoreError::UnterminatedSearchPhrase);
+                }
+                if !phrase.trim().is_empty() {
+                    query.phrases.push(phrase.trim().to_lowercase());
+                }
+                continue;
+            }
+
+            let mut word = String::from(c);
+            while let Some(&n) = chars.peek() {
+                if n.is_whitespace() {
+                    break;
+                }
+                word.push(n);
+                chars.next();
+            }
+
+            if let Some(id) = word.strip_prefix('#') {
+                query.id = Some(
+                    id.parse()
+                        .map_err(|_| CoreError::InvalidSearchValue("#".into(), id.into()))?,
+                );
+            } else if let Some((field, value)) = word.split_once(':') {
+                if value.is_empty() {
+                    return Err(CoreError::InvalidSearchValue(field.into(), value.into()));
+                }
+                match field {
+                    "d" | "difficulty" => query.difficulty = Some(parse_difficulty(value)?),
+                    "t" | "topic" => query.topics.push(value.to_lowercase()),
+                    "s" | "status" => query.status = Some(value.parse()?),
+                    _ => return Err(CoreError::UnknownSearchField(field.into())),
+                }
+            } else {
+                query.fuzzy.push(word);
+            }
+        }
+        Ok(query)
+    }
+}
+
+impl SearchQuery {
+    pub(crate) fn matches(&self, question: &DbQuestion, matcher: &SkimMatcherV2) -> bool {
+        if let Some(id) = self.id {
+            if question.id != id {
+                return false;
+            }
+        }
+
+        if let Some(difficulty) = &self.difficulty {
+            if &question.difficulty != difficulty {
+                return false;
+            }
+        }
+
+        if let Some(status) = &self.status {
+            if !status.matches(question.status.as_deref()) {
+                return false;
+            }
+        }
+
+        if !self
+            .topics
+            .iter()
+            .all(|slug| question.topics.iter().any(|t| &t.slug == slug))
+        {
+            return false;
+        }
+
+        let title = question.title.to_lowercase();
+        if !self.phrases.iter().all(|p| title.contains(p.as_str())) {
+            return false;
+        }
+
+        if self.fuzzy.is_empty() {
+            return true;
+        }
+        matcher
+            .fuzzy_match(question.title.as_str(), self.fuzzy.join(" ").as_str())
+            .is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_field_filters_and_fuzzy_text() {
+        let query: SearchQuery = r#"d:hard t:dynamic-programming s:todo #123 "exact phrase" sum"#
+            .parse()
+            .unwrap();
+        assert_eq!(
+            query,
+            SearchQuery {
+                difficulty: Some("Hard".into()),
+                topics: vec!["dynamic-programming".into()],
+                status: Some(StatusFilter::Todo),
+                id: Some(123),
+                phrases: vec!["exact phrase".into()],
+                fuzzy: vec!["sum".into()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_reports_parse_errors() {
+        assert!(matches!(
+            "x:foo".parse::<SearchQuery>(),
+            Err(CoreError::UnknownSearchField(_))
+        ));
+        assert!(matches!(
+            "d:impossible".parse::<SearchQuery>(),
+            Err(CoreError::InvalidSearchValue(..))
+        ));
+        assert!(matches!(
+            "#abc".parse::<SearchQuery>(),
+            Err(CoreError::InvalidSearchValue(..))
+        ));
+        assert!(matches!(
+            r#""two sum"#.parse::<SearchQuery>(),
+            Err(CoreError::UnterminatedSearchPhrase)
+        ));
+    }
+
+    #[test]
+    fn test_matches_question() {
+        let mut question = DbQuestion::new(
+            1,
+            "Two Sum",
+            "two-sum",
+            "Easy".into(),
+            false,
+            Some("ac".into()),
+        );
+        question.topics.push(leetcode_tui_db::DbTopic::new("array"));
+        let matcher = SkimMatcherV2::default();
+
+        let query: SearchQuery = "d:easy t:array s:solved two".parse().unwrap();
+        assert!(query.matches(&question, &matcher));
+
+        let query: SearchQuery = "t:hash-table".parse().unwrap();
+        assert!(!query.matches(&question, &matcher));
+
+        let query: SearchQuery = r#""three sum""#.parse().unwrap();
+        assert!(!query.matches(&question, &matcher));
+
+        // fuzzy text only matches the title, topics need t:
+        let query: SearchQuery = "array".parse().unwrap();
+        assert!(!query.matches(&question, &matcher));
+    }
+}
@@ -2,14 +2,18 @@ use indexmap::IndexSet;
 use std::{collections::HashMap, hash::Hash, path::PathBuf, sync::RwLock};
 use tokio::fs::read_to_string;
 
-use leetcode_tui_config::CONFIG;
 use leetcode_core::types::language::Language;
+use leetcode_tui_config::CONFIG;
 use regex::Regex;
 use std::sync::OnceLock;
 
 use crate::errors::{CoreError, CoreResult};
 pub static FILENAME_REGEX: OnceLock<regex::Regex> = OnceLock::new();
 pub static SOLUTION_FILE_MANAGER: OnceLock<RwLock<SolutionFileManager>> = OnceLock::new();
+/// Variant used when the given one has no usable characters left.
+const DEFAULT_VARIANT: &str = "community";
+/// Numbered copies tried before giving up on a free variant file name.
+const MAX_VARIANT_FILES: u32 = 1000;
 
 pub(crate) fn init() {
     SOLUTION_FILE_MANAGER
@@ -22,6 +26,9 @@ pub struct SolutionFile {
     pub(crate) question_id: String,
     pub(crate) title_slug: String,
     pub(crate) language: Language,
+    /// Tells apart several solutions in the same language, e.g. the author
+    /// of a saved community solution.
+    variant: Option<String>,
 }
 
 impl Hash for SolutionFile {
@@ -35,6 +42,14 @@ impl SolutionFile {
     pub async fn read_contents(&self) -> CoreResult<String> {
         Ok(read_to_string(&self.path).await?)
     }
+
+    /// Language of the solution, followed by its variant if any.
+    pub(crate) fn label(&self) -> String {
+        match &self.variant {
+            Some(variant) => format!("{} ({variant})", self.language),
+            None => self.language.to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -64,11 +79,51 @@ impl SolutionFileManager {
         Ok(file_path.clone())
     }
 
-    pub(crate) fn get_available_languages(&self, question_id: &str) -> CoreResult<Vec<&Language>> {
+    /// Writes `contents` to a new solution file named after `variant`, e.g.
+    /// `0001_two-sum_11.alice.py`, numbering the variant rather than
+    /// overwriting an existing file.
+    pub(crate) fn create_variant_solution_file(
+        &mut self,
+        question_id: u32,
+        title_slug: &str,
+        language: &Language,
+        variant: &str,
+        contents: &str,
+    ) -> CoreResult<PathBuf> {
+        let mut variant: String = variant
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .collect();
+        if variant.is_empty() {
+            variant = DEFAULT_VARIANT.into();
+        }
+        let lang_id: u32 = language.clone().into();
+        let sol = &CONFIG.as_ref().solutions_dir;
+        let file_path = (1..=MAX_VARIANT_FILES)
+            .map(|n| match n {
+                1 => variant.clone(),
+                n => format!("{variant}-{n}"),
+            })
+            .map(|variant| {
+                sol.join(format!(
+                    "{question_id:0>4}_{title_slug}_{lang_id}.{variant}.{}",
+                    language.get_extension()
+                ))
+            })
+            .find(|path| !path.exists())
+            .ok_or(CoreError::VariantFileNamesExhausted(variant, question_id))?;
+        std::fs::write(file_path.as_path(), contents)?;
+        self.add_solution_file(file_path.clone().try_into()?);
+        Ok(file_path)
+    }
+
+    /// Labels of the solutions of the question, in the order of
+    /// [`Self::get_solution_file`].
+    pub(crate) fn get_solution_labels(&self, question_id: &str) -> CoreResult<Vec<String>> {
         self.id_language_map
             .get(question_id)
             .ok_or(CoreError::QuestionIdDoesNotExist(question_id.into()))
-            .map(|v| v.iter().map(|sf| &sf.language).collect::<Vec<_>>())
+            .map(|v| v.iter().map(|sf| sf.label()).collect::<Vec<_>>())
     }
 
     pub(crate) fn get_solution_file(
@@ -108,8 +163,10 @@ impl TryFrom<PathBuf> for SolutionFile {
 
     fn try_from(value: PathBuf) -> Result<Self, Self::Error> {
         let regex = FILENAME_REGEX.get_or_init(|| {
-            Regex::new(r"0*(?P<q_id>\d*?)_(?P<slug>[\w-]*?)_(?P<lang_id>\d+).(?P<ext>\w+)")
-                .expect("Could not compile regex.")
+            Regex::new(
+                r"0*(?P<q_id>\d*?)_(?P<slug>[\w-]*?)_(?P<lang_id>\d+)(?:\.(?P<variant>[\w-]+))?.(?P<ext>\w+)",
+            )
+            .expect("Could not compile regex.")
         });
 
         let err = Err(CoreError::FileNameFormatDoesNotMatch(value.clone()));
@@ -125,8 +182,8 @@ impl TryFrom<PathBuf> for SolutionFile {
                 .ok_or(CoreError::Utf8ValidityError(value.clone()))?,
         );
 
-        let ids = ["q_id", "slug", "lang_id"];
-        let mut res: [Option<&str>; 3] = [None, None, None];
+        let ids = ["q_id", "slug", "lang_id", "variant"];
+        let mut res: [Option<&str>; 4] = [None, None, None, None];
         if let Some(_captures) = captures {
             for (i, cap_id) in ids.iter().enumerate() {
                 res[i] = _captures.name(cap_id).map(|v| v.as_str());
@@ -137,14 +194,37 @@ impl TryFrom<PathBuf> for SolutionFile {
 
         if let (Some(qid), Some(slug), Some(lang_id)) = (res[0], res[1], res[2]) {
             let lang: Language = lang_id.parse::<u32>()?.into();
+            let variant = res[3].map(String::from);
             Ok(Self {
                 question_id: qid.to_string(),
                 title_slug: slug.to_string(),
                 path: value,
                 language: lang,
+                variant,
             })
         } else {
             err
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_solution_file_names() {
+        let file: SolutionFile = PathBuf::from("0001_two-sum_11.py").try_into().unwrap();
+        assert_eq!(
+            (file.question_id.as_str(), file.title_slug.as_str()),
+            ("1", "two-sum")
+        );
+        assert_eq!(file.label(), "python3");
+        let file: SolutionFile = PathBuf::from("0001_two-sum_0.alice-2.cpp")
+            .try_into()
+            .unwrap();
+        assert_eq!(file.title_slug, "two-sum");
+        assert_eq!(file.language, Language::Cpp);
+        assert_eq!(file.label(), "cpp (alice-2)");
+    }
+}
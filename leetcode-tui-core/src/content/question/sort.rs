@@ -0,0 +1,77 @@
+use std::fmt::Display;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use leetcode_tui_db::DbQuestion;
+
+use crate::errors::CoreError;
+
+/// Order of the question list, set with `:sort`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum QuestionSort {
+    /// The order the topic lists its questions in, e.g. the study order of
+    /// a curated list.
+    #[default]
+    Default,
+    Id,
+    Title,
+    Difficulty,
+    /// Highest acceptance rate first.
+    Acceptance,
+    /// Questions never attempted first, then attempted, then solved.
+    Status,
+}
+
+impl QuestionSort {
+    pub const ALL: [QuestionSort; 6] = [
+        QuestionSort::Default,
+        QuestionSort::Id,
+        QuestionSort::Title,
+        QuestionSort::Difficulty,
+        QuestionSort::Acceptance,
+        QuestionSort::Status,
+    ];
+
+    pub(super) fn sort(self, questions: &mut [Rc<DbQuestion>]) {
+        match self {
+            QuestionSort::Default => {}
+            QuestionSort::Id => questions.sort_by_key(|q| q.id),
+            QuestionSort::Title => questions.sort_by(|a, b| a.title.cmp(&b.title)),
+            QuestionSort::Difficulty => questions.sort_by_key(|q| match q.difficulty.as_str() {
+                "Easy" => 0,
+                "Medium" => 1,
+                _ => 2,
+            }),
+            QuestionSort::Acceptance => questions.sort_by(|a, b| b.ac_rate.total_cmp(&a.ac_rate)),
+            QuestionSort::Status => questions.sort_by_key(|q| match q.status.as_deref() {
+                None => 0,
+                Some("ac") => 2,
+                Some(_) => 1,
+            }),
+        }
+    }
+}
+
+impl Display for QuestionSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestionSort::Default => write!(f, "default"),
+            QuestionSort::Id => write!(f, "id"),
+            QuestionSort::Title => write!(f, "title"),
+            QuestionSort::Difficulty => write!(f, "difficulty"),
+            QuestionSort::Acceptance => write!(f, "acceptance"),
+            QuestionSort::Status => write!(f, "status"),
+        }
+    }
+}
+
+impl FromStr for QuestionSort {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|sort| sort.to_string() == s.to_lowercase())
+            .ok_or(CoreError::CommandUsage(crate::command::SORT_USAGE))
+    }
+}
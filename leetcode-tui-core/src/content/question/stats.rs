@@ -37,9 +37,9 @@ impl Display for QuestionStatus {
     }
 }
 
-impl Into<Style> for QuestionStatus {
-    fn into(self) -> Style {
-        match self {
+impl From<QuestionStatus> for Style {
+    fn from(value: QuestionStatus) -> Self {
+        match value {
             Accepted => CONFIG.as_ref().theme.question.normal.easy,
             Attempted => CONFIG.as_ref().theme.question.normal.easy,
             EasyAccepted => CONFIG.as_ref().theme.question.normal.easy,
@@ -1,50 +1,434 @@
+mod group;
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
 use crate::emit;
+use crate::errors::{CoreError, CoreResult};
 use crate::utils::Paginate;
+use crate::SendError;
+pub use group::TopicGroup;
 use leetcode_tui_config::clients::Db;
-use leetcode_tui_db::DbTopic;
+use leetcode_tui_config::DB_CLIENT;
+use leetcode_tui_db::{
+    CuratedList, DbList, DbReview, DbTopic, DbTopicStats, ResolvedList, TopicSetOp,
+};
 use leetcode_tui_shared::layout::Window;
+use ratatui::widgets::ScrollbarState;
+
+/// Order of the topics within each group of the topic pane. The pseudo-topics
+/// ("Due for review", `all`, the curated lists and the user's lists) always
+/// stay on top.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TopicSort {
+    #[default]
+    Name,
+    Size,
+    Completion,
+}
+
+impl TopicSort {
+    fn next(self) -> Self {
+        match self {
+            TopicSort::Name => TopicSort::Size,
+            TopicSort::Size => TopicSort::Completion,
+            TopicSort::Completion => TopicSort::Name,
+        }
+    }
+}
+
+impl Display for TopicSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TopicSort::Name => write!(f, "name"),
+            TopicSort::Size => write!(f, "size"),
+            TopicSort::Completion => write!(f, "completion"),
+        }
+    }
+}
+
+/// A row of the topic pane: a topic, or the header of the group of topics
+/// below it. Headers are skipped when moving through the pane.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TopicRow {
+    Header(TopicGroup),
+    Topic(DbTopic),
+}
+
+impl TopicRow {
+    pub fn topic(&self) -> Option<&DbTopic> {
+        match self {
+            TopicRow::Topic(topic) => Some(topic),
+            TopicRow::Header(_) => None,
+        }
+    }
+
+    fn is_header(&self) -> bool {
+        matches!(self, TopicRow::Header(_))
+    }
+}
 
 pub struct Topic {
-    paginate: Paginate<DbTopic>,
+    paginate: Paginate<TopicRow>,
+    topics: Vec<DbTopic>,
+    stats: HashMap<String, DbTopicStats>,
+    due_count: usize,
+    lists: Vec<DbList>,
+    curated: HashMap<String, ResolvedList>,
+    sort: TopicSort,
+    marked: Vec<DbTopic>,
+    set_op: TopicSetOp,
 }
 
 impl<'a> Topic {
     pub(crate) async fn new(db: &Db<'a>) -> Self {
-        let mut topics = vec![DbTopic::new("all")];
-        topics.extend(DbTopic::fetch_all(db).unwrap());
-        let s = Self {
-            paginate: Paginate::new(topics),
+        let mut s = Self {
+            paginate: Paginate::new(vec![]),
+            topics: DbTopic::fetch_all(db).unwrap(),
+            stats: Default::default(),
+            due_count: Default::default(),
+            lists: Default::default(),
+            curated: Default::default(),
+            sort: TopicSort::default(),
+            marked: vec![],
+            set_op: TopicSetOp::default(),
         };
+        s.refresh();
+        s.paginate.update_list(s.sorted_topics());
+        // start on `all` rather than on the (often empty) review list
+        s.paginate.jump_to(1, s.widget_height());
         s.notify_change();
         s
     }
 
     pub fn next_topic(&mut self) -> bool {
         let has_topic_changed = self.paginate.next_elem(self.widget_height());
+        self.skip_header(true);
         if has_topic_changed {
             self.notify_change();
         }
         has_topic_changed
     }
 
+    /// Moves off a group header onto the first topic of its group, or onto
+    /// the last topic of the group above when moving backwards.
+    fn skip_header(&mut self, forward: bool) {
+        if self.paginate.hovered().is_some_and(TopicRow::is_header) {
+            if forward {
+                self.paginate.next_elem(self.widget_height());
+            } else {
+                self.paginate.prev_elem(self.widget_height());
+            }
+        }
+    }
+
+    /// Index of the row showing the topic with the given slug.
+    fn position_of(&self, slug: &str) -> Option<usize> {
+        self.paginate
+            .list()
+            .iter()
+            .position(|row| row.topic().is_some_and(|t| t.slug == slug))
+    }
+
     pub fn notify_change(&self) {
-        if let Some(hovered) = self.hovered() {
+        if !self.marked.is_empty() {
+            emit!(Topics(self.marked.clone(), self.set_op));
+        } else if let Some(hovered) = self.hovered() {
             emit!(Topic(hovered.clone()));
         }
     }
 
+    /// Hovers the topic with the given slug and shows its questions.
+    pub fn select(&mut self, slug: &str) -> CoreResult<bool> {
+        let position = self
+            .position_of(slug)
+            .ok_or_else(|| CoreError::TopicNotFound(slug.into()))?;
+        self.paginate.jump_to(position, self.widget_height());
+        self.notify_change();
+        Ok(true)
+    }
+
+    /// Clears the marks and shows the `all` topic.
+    pub fn show_all(&mut self) -> CoreResult<bool> {
+        self.marked.clear();
+        self.select("all")
+    }
+
+    /// Slugs of the topics in the order of the pane.
+    pub fn slugs(&self) -> Vec<String> {
+        self.paginate
+            .list()
+            .iter()
+            .filter_map(TopicRow::topic)
+            .map(|t| t.slug.clone())
+            .collect()
+    }
+
+    /// Marks the hovered topic, or unmarks it if it was already marked.
+    pub fn toggle_mark(&mut self) -> bool {
+        let Some(hovered) = self.hovered().cloned() else {
+            return false;
+        };
+        if let Some(pos) = self.marked.iter().position(|t| t.slug == hovered.slug) {
+            self.marked.remove(pos);
+        } else {
+            self.marked.push(hovered);
+        }
+        self.notify_change();
+        true
+    }
+
+    pub fn clear_marks(&mut self) -> bool {
+        if self.marked.is_empty() {
+            return false;
+        }
+        self.marked.clear();
+        self.notify_change();
+        true
+    }
+
+    /// Switches between intersecting and unioning the marked topics.
+    pub fn toggle_set_op(&mut self) -> bool {
+        self.set_op = self.set_op.toggle();
+        if self.marked.len() > 1 {
+            self.notify_change();
+        }
+        true
+    }
+
+    /// Reloads the per-topic counts and the lists after questions changed.
+    pub fn refresh(&mut self) {
+        if let Ok(curated) = CuratedList::resolve_all(DB_CLIENT.as_ref()).emit_if_error() {
+            self.curated = curated
+                .into_iter()
+                .map(|(list, resolved)| (DbTopic::curated(list).slug, resolved))
+                .collect();
+        }
+        if let Ok(stats) = DbTopicStats::fetch_all(DB_CLIENT.as_ref()).emit_if_error() {
+            self.stats = stats.into_iter().map(|s| (s.slug.clone(), s)).collect();
+        }
+        if let Ok(count) =
+            DbReview::due_count(leetcode_tui_db::models::review::today(), DB_CLIENT.as_ref())
+                .emit_if_error()
+        {
+            self.due_count = count;
+        }
+        if let Ok(lists) = DbList::fetch_all(DB_CLIENT.as_ref()).emit_if_error() {
+            let names_changed = lists.len() != self.lists.len()
+                || lists
+                    .iter()
+                    .zip(self.lists.iter())
+                    .any(|(a, b)| a.name != b.name);
+            self.lists = lists;
+            if names_changed && !self.paginate.list().is_empty() {
+                self.rebuild_keeping_hovered();
+            }
+        }
+    }
+
+    fn rebuild_keeping_hovered(&mut self) {
+        let hovered = self.hovered().map(|t| t.slug.clone());
+        self.paginate.update_list(self.sorted_topics());
+        let position = hovered.and_then(|slug| self.position_of(&slug));
+        self.paginate
+            .jump_to(position.unwrap_or_default(), self.widget_height());
+    }
+
+    /// Number of questions in the list shown by the topic, if it shows one.
+    pub fn list_len(&self, topic: &DbTopic) -> Option<usize> {
+        let name = topic.list_name()?;
+        self.lists
+            .iter()
+            .find(|l| l.name == name)
+            .map(|l| l.question_ids.len())
+    }
+
+    /// Lists the title slugs of the hovered curated list with no question in
+    /// the local database.
+    pub fn show_missing(&self) -> bool {
+        let Some((topic, curated)) = self
+            .hovered()
+            .and_then(|t| Some((t, self.curated(t)?)))
+        else {
+            emit!(Popup(vec![
+                "Hover a curated list in the Topics pane to see its missing questions.".into()
+            ]));
+            return false;
+        };
+        if curated.missing.is_empty() {
+            emit!(Popup(vec![format!(
+                "Every question of {} is in the local database.",
+                topic.name
+            )]));
+        } else {
+            emit!(Popup(
+                format!("Missing from {}", topic.name),
+                curated.missing.iter().map(|s| s.to_string()).collect()
+            ));
+        }
+        false
+    }
+
+    /// The curated list shown by the topic resolved against the local
+    /// questions, if it shows one.
+    pub fn curated(&self, topic: &DbTopic) -> Option<&ResolvedList> {
+        self.curated.get(&topic.slug)
+    }
+
+    pub fn stats(&self, topic: &DbTopic) -> Option<&DbTopicStats> {
+        self.stats.get(&topic.slug)
+    }
+
+    /// The pseudo-topics followed by the topics grouped by [`TopicGroup`],
+    /// each group under its header and ordered by the current [`TopicSort`].
+    fn sorted_topics(&self) -> Vec<TopicRow> {
+        let mut topics = self.topics.clone();
+        let key = |t: &DbTopic| self.stats.get(&t.slug).cloned().unwrap_or_default();
+        match self.sort {
+            TopicSort::Name => topics.sort_by(|a, b| a.name.cmp(&b.name)),
+            TopicSort::Size => topics.sort_by_key(|t| std::cmp::Reverse(key(t).total)),
+            TopicSort::Completion => {
+                topics.sort_by(|a, b| key(b).completion().total_cmp(&key(a).completion()))
+            }
+        }
+        // stable sort, keeps the order chosen above within a group
+        topics.sort_by_key(|t| TopicGroup::of(&t.slug));
+        let mut lists: Vec<DbTopic> = self.lists.iter().map(|l| DbTopic::list(&l.name)).collect();
+        lists.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut pseudo = vec![DbTopic::due_for_review(), DbTopic::new("all")];
+        pseudo.extend(leetcode_tui_db::CURATED_LISTS.iter().map(DbTopic::curated));
+        pseudo.extend(lists);
+        let mut rows: Vec<TopicRow> = pseudo.into_iter().map(TopicRow::Topic).collect();
+        let mut group = None;
+        for topic in topics {
+            let topic_group = TopicGroup::of(&topic.slug);
+            if group != Some(topic_group) {
+                group = Some(topic_group);
+                rows.push(TopicRow::Header(topic_group));
+            }
+            rows.push(TopicRow::Topic(topic));
+        }
+        rows
+    }
+
+    /// Cycles the topic order between name, size and completion.
+    pub fn cycle_sort(&mut self) -> bool {
+        self.sort = self.sort.next();
+        self.paginate.update_list(self.sorted_topics());
+        self.notify_change();
+        true
+    }
+
+    /// Number of questions due for review today.
+    pub fn due_count(&self) -> usize {
+        self.due_count
+    }
+
+    /// Index of the header of the group shown at `index`, `None` within
+    /// the pseudo-topics on top.
+    fn group_header(&self, index: usize) -> Option<usize> {
+        self.paginate.list()[..=index]
+            .iter()
+            .rposition(TopicRow::is_header)
+    }
+
+    /// Clamps the scroll position to the height of the resized pane.
+    pub fn resize(&mut self) {
+        self.paginate.resize(self.widget_height())
+    }
+
+    /// Hovers the topic shown at `row` of the pane and shows its questions.
+    pub fn hover_row(&mut self, row: usize) -> bool {
+        let position = self.paginate.position();
+        let moved = self.paginate.hover_row(row, self.widget_height());
+        self.skip_header(true);
+        if self.paginate.position() != position {
+            self.notify_change();
+        }
+        moved
+    }
+
+    pub fn scroll(&mut self, delta: isize) -> bool {
+        let position = self.paginate.position();
+        let moved = self.paginate.scroll(delta, self.widget_height());
+        self.skip_header(delta > 0);
+        if self.paginate.position() != position {
+            self.notify_change();
+        }
+        moved
+    }
+
+    fn jump_to(&mut self, index: usize) -> bool {
+        let has_topic_changed = self.paginate.jump_to(index, self.widget_height());
+        if has_topic_changed {
+            self.notify_change();
+        }
+        has_topic_changed
+    }
+
+    /// Hovers the first topic of the next group.
+    pub fn next_group(&mut self) -> bool {
+        let position = self.paginate.position();
+        match self
+            .paginate
+            .list()
+            .iter()
+            .skip(position + 1)
+            .position(TopicRow::is_header)
+        {
+            Some(offset) => self.jump_to(position + offset + 2),
+            None => false,
+        }
+    }
+
+    /// Hovers the first topic of the previous group, or the first
+    /// pseudo-topic from the first group.
+    pub fn prev_group(&mut self) -> bool {
+        if self.paginate.list().is_empty() {
+            return false;
+        }
+        let Some(header) = self.group_header(self.paginate.position()) else {
+            return false;
+        };
+        match header.checked_sub(1).and_then(|i| self.group_header(i)) {
+            Some(previous) => self.jump_to(previous + 1),
+            None => self.jump_to(0),
+        }
+    }
+
+    pub fn sort(&self) -> TopicSort {
+        self.sort
+    }
+
+    pub fn is_marked(&self, topic: &DbTopic) -> bool {
+        self.marked.iter().any(|t| t.slug == topic.slug)
+    }
+
+    pub fn marked(&self) -> &[DbTopic] {
+        &self.marked
+    }
+
+    pub fn set_op(&self) -> TopicSetOp {
+        self.set_op
+    }
+
     pub fn prev_topic(&mut self) -> bool {
         let has_topic_changed = self.paginate.prev_elem(self.widget_height());
+        self.skip_header(false);
         if has_topic_changed {
             self.notify_change()
         };
         has_topic_changed
     }
 
-    pub fn window(&self) -> &[DbTopic] {
+    pub fn window(&self) -> &[TopicRow] {
         self.paginate.window(self.widget_height())
     }
 
+    pub fn scrollbar(&self) -> Option<ScrollbarState> {
+        self.paginate.scrollbar(self.widget_height())
+    }
+
     fn widget_height(&self) -> usize {
         let window = Window::default();
         let height = window.root.center_layout.topic.inner.height;
@@ -54,6 +438,6 @@ impl<'a> Topic {
 
 impl Topic {
     pub fn hovered(&self) -> Option<&DbTopic> {
-        self.paginate.hovered()
+        self.paginate.hovered().and_then(TopicRow::topic)
     }
 }
@@ -0,0 +1,124 @@
+use std::fmt::Display;
+
+use leetcode_tui_db::DbTopic;
+
+/// Coarse category of a topic, used to group the topics pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TopicGroup {
+    DataStructures,
+    Algorithms,
+    Database,
+    Concurrency,
+    Other,
+}
+
+impl TopicGroup {
+    pub fn of(slug: &str) -> Self {
+        match slug {
+            "array"
+            | "string"
+            | "hash-table"
+            | "linked-list"
+            | "doubly-linked-list"
+            | "stack"
+            | "queue"
+            | "monotonic-stack"
+            | "monotonic-queue"
+            | "heap-priority-queue"
+            | "tree"
+            | "binary-tree"
+            | "binary-search-tree"
+            | "graph"
+            | "trie"
+            | "segment-tree"
+            | "binary-indexed-tree"
+            | "union-find"
+            | "matrix"
+            | "ordered-set"
+            | "suffix-array"
+            | "iterator"
+            | "data-stream" => TopicGroup::DataStructures,
+            "dynamic-programming"
+            | "greedy"
+            | "sorting"
+            | "backtracking"
+            | "breadth-first-search"
+            | "depth-first-search"
+            | "binary-search"
+            | "two-pointers"
+            | "sliding-window"
+            | "prefix-sum"
+            | "bit-manipulation"
+            | "bitmask"
+            | "recursion"
+            | "divide-and-conquer"
+            | "memoization"
+            | "topological-sort"
+            | "shortest-path"
+            | "minimum-spanning-tree"
+            | "strongly-connected-component"
+            | "biconnected-component"
+            | "eulerian-circuit"
+            | "string-matching"
+            | "rolling-hash"
+            | "hash-function"
+            | "bucket-sort"
+            | "counting-sort"
+            | "radix-sort"
+            | "merge-sort"
+            | "quickselect"
+            | "randomized"
+            | "reservoir-sampling"
+            | "rejection-sampling"
+            | "line-sweep"
+            | "counting"
+            | "enumeration"
+            | "simulation"
+            | "game-theory"
+            | "math"
+            | "geometry"
+            | "number-theory"
+            | "combinatorics"
+            | "probability-and-statistics"
+            | "brainteaser" => TopicGroup::Algorithms,
+            "database" => TopicGroup::Database,
+            "concurrency" => TopicGroup::Concurrency,
+            _ => TopicGroup::Other,
+        }
+    }
+}
+
+impl Display for TopicGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TopicGroup::DataStructures => write!(f, "Data Structures"),
+            TopicGroup::Algorithms => write!(f, "Algorithms"),
+            TopicGroup::Database => write!(f, "Database"),
+            TopicGroup::Concurrency => write!(f, "Concurrency"),
+            TopicGroup::Other => write!(f, "Other"),
+        }
+    }
+}
+
+impl TopicGroup {
+    /// Group of the topic, `None` for pseudo-topics.
+    pub fn of_topic(topic: &DbTopic) -> Option<Self> {
+        (!topic.is_pseudo()).then(|| Self::of(&topic.slug))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_groups_topics() {
+        assert_eq!(TopicGroup::of("string"), TopicGroup::DataStructures);
+        assert_eq!(
+            TopicGroup::of("dynamic-programming"),
+            TopicGroup::Algorithms
+        );
+        assert_eq!(TopicGroup::of("shell"), TopicGroup::Other);
+        assert_eq!(TopicGroup::of_topic(&DbTopic::new("all")), None);
+    }
+}
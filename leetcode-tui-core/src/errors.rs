@@ -21,6 +21,30 @@ pub enum CoreError {
 
     #[error("QuestionId: {0} does not exist")]
     QuestionIdDoesNotExist(String),
+
+    #[error("Unknown search field: {0}")]
+    UnknownSearchField(String),
+
+    #[error("Invalid value for {0}: '{1}'")]
+    InvalidSearchValue(String, String),
+
+    #[error("Unterminated quote in search")]
+    UnterminatedSearchPhrase,
+
+    #[error("Unknown command: {0}")]
+    UnknownCommand(String),
+
+    #[error("Usage: {0}")]
+    CommandUsage(&'static str),
+
+    #[error("No question with id or slug '{0}'")]
+    QuestionNotFound(String),
+
+    #[error("No topic '{0}'")]
+    TopicNotFound(String),
+
+    #[error("Too many '{0}' solution files for question {1}")]
+    VariantFileNamesExhausted(String, u32),
 }
 
 pub type CoreResult<T> = Result<T, CoreError>;
@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
-use crossterm::event::KeyEvent;
-use leetcode_tui_db::{DbQuestion, DbTopic};
+use crossterm::event::{KeyEvent, MouseEvent};
+use leetcode_tui_db::{DbQuestion, DbTopic, TopicSetOp};
 use leetcode_tui_shared::RoCell;
 
 use tokio::sync::{mpsc::UnboundedSender, oneshot};
@@ -11,23 +11,35 @@ static TX: RoCell<UnboundedSender<Event>> = RoCell::new();
 pub enum Event {
     Quit,
     Key(KeyEvent),
+    Mouse(MouseEvent),
     Render(String),
     Resume,
     Suspend,
     Resize(u16, u16),
     Topic(DbTopic),
+    Topics(Vec<DbTopic>, TopicSetOp),
     Questions(Vec<DbQuestion>),
     QuestionFilter(Option<String>),
-    Popup(Option<String>, Vec<String>),
+    /// Shows the lines in a popup, signalling the sender once it is closed.
+    Popup(Option<String>, Vec<String>, Option<oneshot::Sender<()>>),
     SelectPopup(
         Option<String>,
         Vec<String>,
         tokio::sync::oneshot::Sender<Option<usize>>,
     ),
     Input(super::UBStrSender, Option<String>),
-    Open(PathBuf),
+    Prompt(String, Option<String>, oneshot::Sender<Option<String>>),
+    /// Opens the file in the editor, signalling the sender once it is closed.
+    Open(PathBuf, Option<oneshot::Sender<()>>),
     Error(String),
     QuestionUpdate,
+    /// The question with this slug stayed hovered long enough to preview it.
+    PreviewFetch(String),
+    PreviewLoaded(String, Result<crate::preview::Description, String>),
+    /// Hovers the question with this id or title slug.
+    Goto(String),
+    /// Opens the title and markdown in the article reader.
+    Article(String, String, crate::article::ArticleSource),
 }
 
 impl Event {
@@ -61,15 +73,22 @@ macro_rules! emit {
     (Topic($topic:expr)) => {
         $crate::Event::Topic($topic).emit();
     };
+    (Topics($topics:expr, $op:expr)) => {
+        $crate::Event::Topics($topics, $op).emit();
+    };
     (Questions($questions:expr)) => {
         $crate::Event::Questions($questions).emit();
     };
     (Popup($lines:expr)) => {
-        $crate::Event::Popup(None, $lines).emit();
+        $crate::Event::Popup(None, $lines, None).emit();
     };
     (Popup($title:expr, $lines:expr)) => {
-        $crate::Event::Popup(Some($title.into()), $lines).emit();
+        $crate::Event::Popup(Some($title.into()), $lines, None).emit();
     };
+    (PopupWait($lines:expr)) => {{
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        $crate::Event::Popup(None, $lines, Some(tx)).wait(rx)
+    }};
     (SelectPopup($a: expr)) => {{
         let (tx, rx) = tokio::sync::oneshot::channel();
         $crate::Event::SelectPopup(None, $a, tx).wait(rx)
@@ -82,13 +101,33 @@ macro_rules! emit {
         $crate::Event::Error($e).emit();
     };
     (Open($e:expr)) => {
-        $crate::Event::Open($e).emit();
+        $crate::Event::Open($e, None).emit();
     };
+    (OpenWait($e:expr)) => {{
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        $crate::Event::Open($e, Some(tx)).wait(rx)
+    }};
     (Input($e:expr)) => {{
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
         $crate::Event::Input(tx, $e).emit();
         rx
     }};
+    (Prompt($prompt:expr)) => {{
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        $crate::Event::Prompt($prompt.into(), None, tx).wait(rx)
+    }};
+    (PreviewFetch($slug:expr)) => {
+        $crate::Event::PreviewFetch($slug).emit();
+    };
+    (PreviewLoaded($slug:expr, $result:expr)) => {
+        $crate::Event::PreviewLoaded($slug, $result).emit();
+    };
+    (Goto($id_or_slug:expr)) => {
+        $crate::Event::Goto($id_or_slug).emit();
+    };
+    (Article($title:expr, $markdown:expr, $source:expr)) => {
+        $crate::Event::Article($title, $markdown, $source).emit();
+    };
     (QuestionFilter($e:expr)) => {
         $crate::Event::QuestionFilter($e).emit();
     };
@@ -1,34 +1,79 @@
+use leetcode_tui_config::key::KeySeq;
+use leetcode_tui_config::keymap::{Action, Context};
+use leetcode_tui_config::CONFIG;
 use ratatui::widgets::*;
 
+/// Contexts shown in the help, in order.
+const SECTIONS: [Context; 7] = [
+    Context::List,
+    Context::Stats,
+    Context::Article,
+    Context::Popup,
+    Context::Select,
+    Context::Input,
+    Context::Help,
+];
+
+/// A bound action of the active keymap.
+pub struct HelpRow {
+    pub context: Context,
+    pub action: Action,
+    pub keys: String,
+    pub description: &'static str,
+}
+
+impl HelpRow {
+    fn matches(&self, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        self.description.to_lowercase().contains(&filter)
+            || self.keys.to_lowercase().contains(&filter)
+            || self.context.to_string().to_lowercase().contains(&filter)
+    }
+}
+
+pub enum HelpLine<'a> {
+    Section(Context),
+    Row(&'a HelpRow),
+}
+
 pub struct Help {
     state: TableState,
-    items: Vec<Vec<&'static str>>,
+    rows: Vec<HelpRow>,
+    filter: String,
+    filtering: bool,
     visible: bool,
 }
 
 impl Default for Help {
     fn default() -> Self {
+        let keymap = &CONFIG.as_ref().keymap;
+        let rows = SECTIONS
+            .into_iter()
+            .flat_map(|context| {
+                keymap
+                    .bindings(context)
+                    .actions()
+                    .into_iter()
+                    .map(move |(action, keys)| HelpRow {
+                        context,
+                        action,
+                        keys: keys
+                            .iter()
+                            .map(KeySeq::to_string)
+                            .collect::<Vec<_>>()
+                            .join("/"),
+                        description: context.describe(action),
+                    })
+            })
+            .collect();
         let mut help = Self {
             state: TableState::default(),
-            items: vec![
-                vec!["t", "Move to Next Topic"],
-                vec!["T", "Move to Previous Topic"],
-                vec!["Ctrl+s", "Show/Hide topic stats"],
-                vec!["j/Down", "Move to Next Question"],
-                vec!["k/Up", "Move to Previous Question"],
-                vec!["r", "Move to Random Question"],
-                vec!["Enter", "Read Question/Selection"],
-                vec!["e", "Open Editor"],
-                vec!["R", "Run Solution"],
-                vec!["s", "Submit Solution"],
-                vec!["/", "Search"],
-                vec!["c", "Open config file"],
-            ],
+            rows,
+            filter: Default::default(),
+            filtering: false,
             visible: Default::default(),
         };
-        if !help.items.is_empty() {
-            help.state.select(Some(0));
-        }
+        help.select_first();
         help
     }
 }
@@ -45,42 +90,117 @@ impl Help {
 impl Help {
     pub fn toggle(&mut self) -> bool {
         self.visible = !self.visible;
+        if self.visible {
+            self.filter.clear();
+            self.filtering = false;
+            self.select_first();
+        }
         true
     }
 
-    pub fn get_items(&self) -> &Vec<Vec<&'static str>> {
-        &self.items
+    /// Section headers followed by the rows matching the filter, sections
+    /// without a matching row are left out.
+    pub fn lines(&self) -> Vec<HelpLine<'_>> {
+        let mut lines = vec![];
+        for context in SECTIONS {
+            let mut rows = self
+                .rows
+                .iter()
+                .filter(|r| r.context == context && r.matches(&self.filter))
+                .peekable();
+            if rows.peek().is_some() {
+                lines.push(HelpLine::Section(context));
+                lines.extend(rows.map(HelpLine::Row));
+            }
+        }
+        lines
+    }
+
+    /// Context and action of the selected row.
+    pub fn selected(&self) -> Option<(Context, Action)> {
+        match self.lines().get(self.state.selected()?)? {
+            HelpLine::Row(row) => Some((row.context, row.action)),
+            HelpLine::Section(_) => None,
+        }
     }
 
-    pub fn next(&mut self) -> bool {
-        let i = match self.state.selected() {
-            Some(i) => {
-                if i >= self.items.len() - 1 {
-                    0
-                } else {
-                    i + 1
-                }
-            }
-            None => 0,
+    fn select_first(&mut self) {
+        let first = self
+            .lines()
+            .iter()
+            .position(|l| matches!(l, HelpLine::Row(_)));
+        self.state.select(first);
+    }
+
+    /// Moves the selection to the next or previous row, wrapping around and
+    /// skipping the section headers.
+    fn step(&mut self, forward: bool) -> bool {
+        let lines = self.lines();
+        let len = lines.len();
+        let Some(mut i) = self.state.selected() else {
+            return false;
         };
+        for _ in 0..len {
+            i = if forward {
+                (i + 1) % len
+            } else {
+                (i + len - 1) % len
+            };
+            if matches!(lines[i], HelpLine::Row(_)) {
+                break;
+            }
+        }
         self.state.select(Some(i));
         true
     }
 
-    pub fn previous(&mut self) -> bool {
-        let i = match self.state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    self.items.len() - 1
-                } else {
-                    i - 1
-                }
-            }
-            None => 0,
-        };
-        self.state.select(Some(i));
+    pub fn next_item(&mut self) -> bool {
+        self.step(true)
+    }
+
+    pub fn prev_item(&mut self) -> bool {
+        self.step(false)
+    }
+
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    pub fn is_filtering(&self) -> bool {
+        self.filtering
+    }
+
+    pub fn start_filtering(&mut self) -> bool {
+        self.filtering = true;
         true
     }
 
-    pub fn get_headers() {}
+    /// Stops typing into the filter, keeping it applied.
+    pub fn stop_filtering(&mut self) -> bool {
+        self.filtering = false;
+        true
+    }
+
+    /// Removes the filter, returns false when there was none.
+    pub fn clear_filter(&mut self) -> bool {
+        self.filtering = false;
+        if self.filter.is_empty() {
+            return false;
+        }
+        self.filter.clear();
+        self.select_first();
+        true
+    }
+
+    pub fn push_filter_char(&mut self, c: char) -> bool {
+        self.filter.push(c);
+        self.select_first();
+        true
+    }
+
+    pub fn pop_filter_char(&mut self) -> bool {
+        self.filter.pop();
+        self.select_first();
+        true
+    }
 }
@@ -1,21 +1,69 @@
+use tokio::sync::oneshot;
+
 use crate::SendError;
 
 #[derive(Default)]
 pub struct Input {
     pub visible: bool,
     current_text: Option<String>,
+    error: Option<String>,
     sender: Option<super::UBStrSender>,
+    prompt: Option<String>,
+    answer: Option<oneshot::Sender<Option<String>>>,
+    command: bool,
+    hint: Option<String>,
 }
 
 impl Input {
     pub fn text(&self) -> Option<&String> {
         self.current_text.as_ref()
     }
+
+    /// Text shown in front of the input, `/` for the search.
+    pub fn prompt(&self) -> &str {
+        if self.command {
+            return ":";
+        }
+        self.prompt.as_deref().unwrap_or("/")
+    }
+
+    /// Whether the `:` command line is open.
+    pub fn is_command(&self) -> bool {
+        self.command
+    }
+
+    /// Text shown after the input, e.g. the completion candidates.
+    pub fn hint(&self) -> Option<&String> {
+        self.hint.as_ref()
+    }
+
+    pub fn set_hint(&mut self, hint: Option<String>) {
+        self.hint = hint;
+    }
+
+    pub fn set_text(&mut self, text: Option<String>) {
+        self.current_text = text;
+        self.hint = None;
+    }
+
+    pub fn error(&self) -> Option<&String> {
+        self.error.as_ref()
+    }
+
+    pub fn set_error(&mut self, error: Option<String>) {
+        self.error = error;
+    }
 }
 
 impl Input {
     pub fn close(&mut self) -> bool {
+        if let Some(answer) = self.answer.take() {
+            answer.send(None).ok();
+        }
         self.current_text = None;
+        self.error = None;
+        self.hint = None;
+        self.command = false;
         if let Some(sender) = self.sender.take() {
             tokio::spawn(async move {
                 let _ = sender.send(None).emit_if_error();
@@ -30,6 +78,7 @@ impl Input {
         } else {
             self.current_text = Some(c.into());
         }
+        self.hint = None;
         self.try_send();
         true
     }
@@ -38,6 +87,7 @@ impl Input {
         if let Some(_text) = self.current_text.as_mut() {
             if !_text.is_empty() {
                 _text.pop();
+                self.hint = None;
                 self.try_send();
             }
         }
@@ -61,5 +111,54 @@ impl Input {
     pub fn reset_with(&mut self, sender: super::UBStrSender, default_input: Option<String>) {
         self.sender = Some(sender);
         self.current_text = default_input;
+        self.error = None;
+        self.prompt = None;
+        self.command = false;
+    }
+
+    /// Asks for a single line of text, answered on `Enter` or dismissed on `Esc`.
+    pub fn reset_for_prompt(
+        &mut self,
+        prompt: String,
+        default_input: Option<String>,
+        answer: oneshot::Sender<Option<String>>,
+    ) {
+        if let Some(previous) = self.answer.replace(answer) {
+            previous.send(None).ok();
+        }
+        self.sender = None;
+        self.current_text = default_input;
+        self.error = None;
+        self.prompt = Some(prompt);
+        self.command = false;
+    }
+
+    /// Opens the `:` command line. The typed line is taken with
+    /// [`Input::take_command`] rather than sent anywhere.
+    pub fn reset_for_command(&mut self) {
+        if let Some(previous) = self.answer.take() {
+            previous.send(None).ok();
+        }
+        self.sender = None;
+        self.current_text = None;
+        self.error = None;
+        self.hint = None;
+        self.prompt = None;
+        self.command = true;
+    }
+
+    /// Closes the command line, returning the typed line.
+    pub fn take_command(&mut self) -> Option<String> {
+        let line = self.current_text.take();
+        self.close();
+        line
+    }
+
+    /// Answers a pending prompt with the typed text, then closes the input.
+    pub fn submit(&mut self) -> bool {
+        if let Some(answer) = self.answer.take() {
+            answer.send(self.current_text.take()).ok();
+        }
+        self.close()
     }
 }
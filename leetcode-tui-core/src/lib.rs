@@ -1,12 +1,15 @@
+pub mod article;
 pub mod event;
 pub mod step;
 pub use event::Event;
 use std::error::Error;
+pub mod command;
 pub mod content;
 pub mod errors;
 pub mod help;
 pub mod input;
 pub mod popup;
+pub mod preview;
 pub mod utils;
 
 pub type UBStrSender = tokio::sync::mpsc::UnboundedSender<Option<String>>;
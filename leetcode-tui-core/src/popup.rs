@@ -10,6 +10,8 @@ pub struct Popup {
     pub v_scroll_state: ScrollbarState,
     pub v_scroll: u16,
     title: Option<String>,
+    /// Signalled once the popup is closed.
+    on_close: Option<tokio::sync::oneshot::Sender<()>>,
 }
 
 impl Popup {
@@ -30,17 +32,34 @@ impl Popup {
 
     pub fn toggle(&mut self) -> bool {
         self.visible = !self.visible;
+        if !self.visible {
+            self.notify_closed();
+        }
         true
     }
 
+    fn notify_closed(&mut self) {
+        if let Some(on_close) = self.on_close.take() {
+            let _ = on_close.send(());
+        }
+    }
+
     pub fn get_text(&self) -> &Vec<String> {
         &self.lines
     }
 
-    pub fn reset(&mut self, title: Option<String>, lines: Vec<String>) {
+    pub fn reset(
+        &mut self,
+        title: Option<String>,
+        lines: Vec<String>,
+        on_close: Option<tokio::sync::oneshot::Sender<()>>,
+    ) {
+        // the replaced popup counts as closed
+        self.notify_closed();
         let mut p = Self::new(lines);
         p.visible = self.visible;
         p.title = title;
+        p.on_close = on_close;
         *self = p;
     }
 
@@ -173,6 +192,16 @@ impl<T: Display> SelectPopup<T> {
         true
     }
 
+    /// Selects the item shown at `row` of the list, e.g. a clicked one.
+    pub fn select_row(&mut self, row: usize) -> bool {
+        let index = self.state.offset() + row;
+        if index >= self.items.len() {
+            return false;
+        }
+        self.state.select(Some(index));
+        true
+    }
+
     pub fn unselect(&mut self) {
         self.state.select(None);
     }
@@ -0,0 +1,313 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+use leetcode_core::{GQLLeetcodeRequest, QuestionContentRequest};
+use leetcode_tui_config::key::KeySeq;
+use leetcode_tui_config::keymap::Action;
+use leetcode_tui_config::{CONFIG, REQ_CLIENT};
+use leetcode_tui_shared::layout::Window;
+
+use crate::emit;
+use crate::utils::wrapped_height;
+
+/// How long a question has to stay hovered before its description is
+/// fetched, so that scrolling through the list does not fetch every row.
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Description of a question as shown in the preview pane.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Description {
+    /// Difficulty, votes, acceptance and topics.
+    pub details: Vec<String>,
+    pub lines: Vec<String>,
+    /// Spoilers, revealed one at a time.
+    pub hints: Vec<String>,
+}
+
+/// A line of the preview pane before wrapping, styled by its kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewLine<'a> {
+    /// Details, notices and the collapsed hints.
+    Muted(Cow<'a, str>),
+    Heading(Cow<'a, str>),
+    Text(Cow<'a, str>),
+    Error(Cow<'a, str>),
+}
+
+impl PreviewLine<'_> {
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Muted(text) | Self::Heading(text) | Self::Text(text) | Self::Error(text) => text,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum PreviewState {
+    Loading,
+    Loaded(Rc<Description>),
+    Failed(String),
+}
+
+/// Pane following the hovered question with its description. Descriptions
+/// are kept for the whole session once fetched.
+#[derive(Default)]
+pub struct Preview {
+    cache: HashMap<String, PreviewState>,
+    /// Title slug of the previewed question.
+    slug: Option<String>,
+    /// Number of hints revealed.
+    hints_shown: usize,
+    scroll: u16,
+}
+
+impl Preview {
+    /// Previews the question with the given slug, fetching its description
+    /// once it stays hovered for [`DEBOUNCE`].
+    pub fn follow(&mut self, slug: Option<&str>) {
+        if self.slug.as_deref() == slug {
+            return;
+        }
+        self.slug = slug.map(String::from);
+        self.hints_shown = 0;
+        self.scroll = 0;
+        // failed fetches are retried when the question is hovered again
+        if let Some(slug) = slug {
+            if let Some(PreviewState::Failed(_)) = self.cache.get(slug) {
+                self.cache.remove(slug);
+            }
+        }
+        if let Some(slug) = slug.filter(|slug| !self.cache.contains_key(*slug)) {
+            let slug = slug.to_string();
+            tokio::spawn(async move {
+                tokio::time::sleep(DEBOUNCE).await;
+                emit!(PreviewFetch(slug));
+            });
+        }
+    }
+
+    /// Fetches the description of `slug` unless another question got
+    /// hovered in the meantime.
+    pub fn fetch(&mut self, slug: String) {
+        if self.slug.as_ref() != Some(&slug) || self.cache.contains_key(&slug) {
+            return;
+        }
+        self.cache.insert(slug.clone(), PreviewState::Loading);
+        tokio::spawn(async move {
+            let result = QuestionContentRequest::new(slug.clone())
+                .send(REQ_CLIENT.as_ref())
+                .await
+                .map(|content| Description {
+                    details: content.data.question.details(),
+                    lines: content
+                        .data
+                        .question
+                        .html_to_text()
+                        .lines()
+                        .map(String::from)
+                        .collect(),
+                    hints: content.data.question.hints_to_text(),
+                })
+                .map_err(|e| e.to_string());
+            emit!(PreviewLoaded(slug, result));
+        });
+    }
+
+    pub fn loaded(&mut self, slug: String, result: Result<Description, String>) {
+        let state = match result {
+            Ok(description) => {
+                if self.slug.as_ref() == Some(&slug) {
+                    self.hints_shown = self.hints_shown.min(description.hints.len());
+                }
+                PreviewState::Loaded(Rc::new(description))
+            }
+            Err(e) => PreviewState::Failed(e),
+        };
+        self.cache.insert(slug, state);
+    }
+
+    /// State of the previewed question, `None` while the fetch is debounced.
+    pub fn state(&self) -> Option<&PreviewState> {
+        self.slug.as_ref().and_then(|slug| self.cache.get(slug))
+    }
+
+    /// Reveals the next hint, or hides them all again once every hint is
+    /// shown. Asking while the description loads reveals the first hint
+    /// once it is loaded.
+    pub fn reveal_hint(&mut self) -> bool {
+        match self.state() {
+            Some(PreviewState::Loaded(d)) if self.hints_shown >= d.hints.len() => {
+                let changed = self.hints_shown > 0;
+                self.hints_shown = 0;
+                changed
+            }
+            Some(PreviewState::Loaded(_)) => {
+                self.hints_shown += 1;
+                true
+            }
+            Some(PreviewState::Failed(_)) => false,
+            None | Some(PreviewState::Loading) => {
+                let changed = self.hints_shown == 0;
+                self.hints_shown = 1;
+                changed
+            }
+        }
+    }
+
+    /// Lines of the pane: the details, description and hints of the
+    /// previewed question, or its loading state.
+    pub fn lines(&self) -> Vec<PreviewLine<'_>> {
+        self.lines_with(CONFIG.as_ref().keymap.list.keys(Action::RevealHint))
+    }
+
+    /// Lines of the pane, naming `reveal_keys` in the collapsed hints.
+    fn lines_with(&self, reveal_keys: Option<&KeySeq>) -> Vec<PreviewLine<'_>> {
+        match self.state() {
+            None | Some(PreviewState::Loading) => vec![PreviewLine::Muted("Loading…".into())],
+            Some(PreviewState::Failed(e)) => vec![PreviewLine::Error(e.into())],
+            Some(PreviewState::Loaded(description)) => {
+                self.description_lines(description, reveal_keys)
+            }
+        }
+    }
+
+    fn description_lines<'a>(
+        &self,
+        description: &'a Description,
+        reveal_keys: Option<&KeySeq>,
+    ) -> Vec<PreviewLine<'a>> {
+        let mut lines = description
+            .details
+            .iter()
+            .map(|l| PreviewLine::Muted(l.into()))
+            .collect::<Vec<_>>();
+        lines.push(PreviewLine::Text("".into()));
+        lines.extend(description.lines.iter().map(|l| {
+            if is_heading(l) {
+                PreviewLine::Heading(l.into())
+            } else {
+                PreviewLine::Text(l.into())
+            }
+        }));
+        if description.hints.is_empty() {
+            return lines;
+        }
+        let shown = self.hints_shown.min(description.hints.len());
+        lines.push(PreviewLine::Heading("Hints".into()));
+        lines.extend(
+            description.hints[..shown]
+                .iter()
+                .enumerate()
+                .map(|(i, hint)| PreviewLine::Text(format!("{}. {hint}", i + 1).into())),
+        );
+        let hidden = description.hints.len() - shown;
+        if hidden > 0 {
+            let mut label = format!("▸ {hidden} more hint{}", if hidden == 1 { "" } else { "s" });
+            if let Some(keys) = reveal_keys {
+                label.push_str(&format!(" ({keys} to reveal the next)"));
+            }
+            lines.push(PreviewLine::Muted(label.into()));
+        }
+        lines
+    }
+
+    pub fn hints_shown(&self) -> usize {
+        self.hints_shown
+    }
+
+    pub fn scroll(&self) -> u16 {
+        self.scroll
+    }
+
+    pub fn scroll_down(&mut self) -> bool {
+        let area = Window::default().root.center_layout.preview.inner;
+        // stops once the last wrapped row is at the bottom of the pane
+        let rows = wrapped_height(self.lines().iter().map(PreviewLine::text), area.width);
+        if self.scroll as usize + area.height as usize >= rows {
+            return false;
+        }
+        self.scroll += 1;
+        true
+    }
+
+    pub fn scroll_up(&mut self) -> bool {
+        if self.scroll == 0 {
+            return false;
+        }
+        self.scroll -= 1;
+        true
+    }
+}
+
+/// Section headings of a description, e.g. `Example 1:` or `Constraints:`.
+fn is_heading(line: &str) -> bool {
+    line.ends_with(':')
+        && ["Example", "Constraints", "Follow"]
+            .iter()
+            .any(|prefix| line.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn description() -> Description {
+        Description {
+            details: vec!["Easy".into()],
+            lines: vec!["Given an array".into(), "Example 1:".into()],
+            hints: vec!["Use a hash map".into(), "Look up the complement".into()],
+        }
+    }
+
+    #[test]
+    fn test_follows_cached_questions() {
+        let mut preview = Preview::default();
+        preview.loaded("two-sum".into(), Ok(description()));
+        preview.loaded("add-two-numbers".into(), Err("offline".into()));
+        preview.follow(Some("two-sum"));
+        assert!(matches!(preview.state(), Some(PreviewState::Loaded(d)) if **d == description()));
+        // the details, a blank line, the description, the heading and the
+        // collapsed hints
+        let lines = preview.lines_with(Some(&"H".parse().unwrap()));
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines.last().map(PreviewLine::text),
+            Some("▸ 2 more hints (H to reveal the next)")
+        );
+        assert!(preview.reveal_hint() && preview.reveal_hint());
+        assert_eq!(preview.hints_shown(), 2);
+        // past the last hint they are hidden again
+        assert!(preview.reveal_hint());
+        assert_eq!(preview.hints_shown(), 0);
+        preview.reveal_hint();
+        // another question resets the scroll and hides the hints again
+        preview.follow(None);
+        preview.follow(Some("two-sum"));
+        assert_eq!((preview.scroll(), preview.hints_shown()), (0, 0));
+        // hints asked for while loading are bounded by the hints loaded
+        preview
+            .cache
+            .insert("add-two-numbers".into(), PreviewState::Loading);
+        preview.follow(Some("add-two-numbers"));
+        assert!(preview.reveal_hint());
+        assert!(!preview.reveal_hint());
+        assert_eq!(preview.hints_shown(), 1);
+        preview.loaded(
+            "add-two-numbers".into(),
+            Ok(Description {
+                hints: vec![],
+                ..description()
+            }),
+        );
+        assert_eq!(preview.hints_shown(), 0);
+        preview.loaded("add-two-numbers".into(), Err("offline".into()));
+        // a fetch is only started for the hovered question
+        preview.fetch("add-two-numbers".into());
+        assert!(matches!(
+            preview.cache.get("add-two-numbers"),
+            Some(PreviewState::Failed(_))
+        ));
+    }
+}
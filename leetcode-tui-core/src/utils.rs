@@ -1,26 +1,28 @@
 use crate::emit;
 use rand::{thread_rng, Rng};
-use std::ops::Range;
+use ratatui::widgets::ScrollbarState;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Rows kept visible above and below the hovered element while scrolling.
+const SCROLL_OFF: usize = 3;
+
+/// A list shown `wid_height` rows at a time with a hovered element. The
+/// shown rows are the `offset..offset + wid_height` slice, so every move is
+/// constant time whatever the length of the list.
 pub struct Paginate<T> {
     list: Vec<T>,
-    nth_window: usize,
-    cursor_range: Range<usize>,
-    cursor: usize,
-    hovered: Option<T>,
+    /// Index of the first shown element.
+    offset: usize,
+    /// Index of the hovered element.
+    index: usize,
 }
 
-impl<T> Paginate<T>
-where
-    T: Clone,
-{
+impl<T> Paginate<T> {
     pub fn new(list: Vec<T>) -> Self {
-        let hovered = list.first().cloned();
         Self {
             list,
-            nth_window: Default::default(),
-            cursor_range: Default::default(),
-            cursor: Default::default(),
-            hovered,
+            offset: 0,
+            index: 0,
         }
     }
 
@@ -29,110 +31,287 @@ where
     }
 }
 
-impl<T> Paginate<T>
-where
-    T: Clone,
-{
-    pub fn next_elem(&mut self, wid_height: usize) -> bool {
+impl<T> Paginate<T> {
+    fn is_empty(&self) -> bool {
         if self.list.is_empty() {
             emit!(Popup(vec!["List is empty".into()]));
             return true;
         }
-        self.set_cursor_range(wid_height);
-        let old_cursor = self.cursor;
-        let old_window = self.nth_window;
-
-        if self.cursor >= self.cursor_range.end {
-            self.nth_window = self.nth_window.saturating_add(1).min(
-                self.list
-                    .windows(self.cursor_upper_bound(wid_height))
-                    .count()
-                    - 1,
-            );
-        } else {
-            self.cursor = (self.cursor + 1).min(self.cursor_upper_bound(wid_height) - 1);
-        }
-        self.hovered = self.window(wid_height).get(self.cursor).cloned();
-        self.cursor != old_cursor || self.nth_window != old_window
+        false
+    }
+
+    pub fn next_elem(&mut self, wid_height: usize) -> bool {
+        !self.is_empty() && self.hover(self.index.saturating_add(1), wid_height)
     }
 
     pub fn prev_elem(&mut self, wid_height: usize) -> bool {
-        if self.list.is_empty() {
-            emit!(Popup(vec!["List is empty".into()]));
-            return true;
-        }
-        self.set_cursor_range(wid_height);
-        let old_cursor = self.cursor;
-        let old_window = self.nth_window;
+        !self.is_empty() && self.hover(self.index.saturating_sub(1), wid_height)
+    }
 
-        if self.cursor < self.cursor_range.start {
-            self.nth_window = self.nth_window.saturating_sub(1);
-        } else {
-            self.cursor = self.cursor.saturating_sub(1);
-        }
+    /// Hovers an element picked uniformly from the whole list.
+    pub fn rand_elem(&mut self, wid_height: usize) -> bool {
+        !self.is_empty() && self.hover(thread_rng().gen_range(0..self.list.len()), wid_height)
+    }
 
-        self.hovered = self.window(wid_height).get(self.cursor).cloned();
-        self.cursor != old_cursor || self.nth_window != old_window
+    pub fn first_elem(&mut self, wid_height: usize) -> bool {
+        !self.is_empty() && self.hover(0, wid_height)
     }
 
-    pub fn rand_elem(&mut self, wid_height: usize) -> bool {
-        if self.list.is_empty() {
-            emit!(Popup(vec!["List is empty".into()]));
-            return true;
-        }
-        self.set_cursor_range(wid_height);
-        let old_cursor = self.cursor;
-        let old_window = self.nth_window;
+    pub fn last_elem(&mut self, wid_height: usize) -> bool {
+        !self.is_empty() && self.hover(self.list.len() - 1, wid_height)
+    }
+
+    pub fn page_down(&mut self, wid_height: usize) -> bool {
+        !self.is_empty() && self.scroll_by(wid_height as isize, wid_height)
+    }
+
+    pub fn page_up(&mut self, wid_height: usize) -> bool {
+        !self.is_empty() && self.scroll_by(-(wid_height as isize), wid_height)
+    }
+
+    pub fn half_page_down(&mut self, wid_height: usize) -> bool {
+        !self.is_empty() && self.scroll_by((wid_height / 2).max(1) as isize, wid_height)
+    }
+
+    pub fn half_page_up(&mut self, wid_height: usize) -> bool {
+        !self.is_empty() && self.scroll_by(-((wid_height / 2).max(1) as isize), wid_height)
+    }
+
+    /// Scrolls by `delta` rows, e.g. for the mouse wheel. Unlike the key
+    /// motions an empty list is left quietly.
+    pub fn scroll(&mut self, delta: isize, wid_height: usize) -> bool {
+        !self.list.is_empty() && self.scroll_by(delta, wid_height)
+    }
+
+    /// Moves both the window and the hovered element by `delta` rows.
+    fn scroll_by(&mut self, delta: isize, wid_height: usize) -> bool {
+        let max_offset = self.list.len().saturating_sub(wid_height);
+        self.offset = self.offset.saturating_add_signed(delta).min(max_offset);
+        self.hover(self.index.saturating_add_signed(delta), wid_height)
+    }
+
+    /// Hovers the element at `index`, clamped to the list, scrolling as
+    /// little as possible to keep it [`SCROLL_OFF`] rows away from the edges
+    /// of the window.
+    fn hover(&mut self, index: usize, wid_height: usize) -> bool {
+        let old = (self.index, self.offset);
+        self.index = index.min(self.list.len().saturating_sub(1));
+        self.offset = self.scrolled_offset(wid_height);
+        old != (self.index, self.offset)
+    }
 
-        self.cursor = thread_rng().gen_range(0..self.cursor_upper_bound(wid_height));
+    /// The offset closest to the current one that shows the hovered element.
+    fn scrolled_offset(&self, wid_height: usize) -> usize {
+        let height = wid_height.max(1);
+        let margin = SCROLL_OFF.min((height - 1) / 2);
+        let lowest = (self.index + margin + 1).saturating_sub(height);
+        let highest = self.index.saturating_sub(margin).max(lowest);
+        let max_offset = self.list.len().saturating_sub(height);
+        self.offset.clamp(lowest, highest).min(max_offset)
+    }
+
+    pub fn window(&self, wid_height: usize) -> &[T] {
+        let offset = self.scrolled_offset(wid_height);
+        &self.list[offset..(offset + wid_height).min(self.list.len())]
+    }
+
+    pub fn hovered(&self) -> Option<&T> {
+        self.list.get(self.index)
+    }
 
-        let upper = self
-            .list
-            .windows(self.cursor_upper_bound(wid_height))
-            .count()
-            - 1;
+    pub fn list(&self) -> &[T] {
+        &self.list
+    }
+
+    /// Index of the hovered element in the whole list.
+    pub fn position(&self) -> usize {
+        self.index
+    }
 
-        if upper > 0 {
-            self.nth_window = thread_rng().gen_range(0..upper);
+    /// Hovers the element at `index`, scrolling the window to show it.
+    pub fn jump_to(&mut self, index: usize, wid_height: usize) -> bool {
+        if index >= self.list.len() {
+            return false;
         }
+        self.hover(index, wid_height)
+    }
 
-        self.hovered = self.window(wid_height).get(self.cursor).cloned();
-        self.cursor != old_cursor || self.nth_window != old_window
+    /// Keeps the hovered element in a window resized to `wid_height` rows.
+    pub fn resize(&mut self, wid_height: usize) {
+        self.offset = self.scrolled_offset(wid_height);
     }
 
-    fn set_cursor_range(&mut self, wid_height: usize) {
-        let mut b = self.cursor_upper_bound(wid_height);
-        if self.cursor_upper_bound(wid_height) >= wid_height {
-            b = self.cursor_upper_bound(wid_height).saturating_sub(3)
+    /// Hovers the element shown at `row` of the window, e.g. a clicked one.
+    pub fn hover_row(&mut self, row: usize, wid_height: usize) -> bool {
+        if row >= wid_height {
+            return false;
+        }
+        let index = self.scrolled_offset(wid_height) + row;
+        index < self.list.len() && self.hover(index, wid_height)
+    }
+
+    /// State of a scrollbar for the list, `None` when it fits the window.
+    pub fn scrollbar(&self, wid_height: usize) -> Option<ScrollbarState> {
+        if self.list.len() <= wid_height {
+            return None;
+        }
+        let clamp = |n: usize| n.min(u16::MAX as usize) as u16;
+        Some(
+            ScrollbarState::default()
+                .content_length(clamp(self.list.len() - 1))
+                .viewport_content_length(clamp(wid_height))
+                .position(clamp(self.index)),
+        )
+    }
+}
+
+/// Splits `line` into rows at most `width` columns wide, breaking after
+/// whitespace and splitting words longer than a row. Panes scrolling wrapped
+/// text draw these rows instead of letting the paragraph wrap, so the rows
+/// they scroll over are the rows they draw. Leading whitespace is kept and
+/// trailing whitespace hangs past the end of the row.
+pub fn wrap(line: &str, width: u16) -> Vec<&str> {
+    let width = width as usize;
+    if width == 0 {
+        return vec![line];
+    }
+    let mut rows = vec![];
+    let (mut start, mut row_width) = (0, 0);
+    for (at, segment) in words(line) {
+        let word = segment.trim_end();
+        let word_width = word.width();
+        if row_width > 0 && row_width + word_width > width {
+            rows.push(&line[start..at]);
+            (start, row_width) = (at, 0);
         }
-        if self.nth_window == 0 {
-            //first window
-            self.cursor_range = 0..b;
-        } else if self.nth_window
-            == (self
-                .list
-                .windows(self.cursor_upper_bound(wid_height))
-                .count()
-                - 1)
-        {
-            // last_window
-            self.cursor_range = 3..self.cursor_upper_bound(wid_height);
+        if word_width > width {
+            for (i, c) in word.char_indices() {
+                let char_width = c.width().unwrap_or_default();
+                if row_width > 0 && row_width + char_width > width {
+                    rows.push(&line[start..at + i]);
+                    (start, row_width) = (at + i, 0);
+                }
+                row_width += char_width;
+            }
+            row_width += segment[word.len()..].width();
         } else {
-            self.cursor_range = 3..self.cursor_upper_bound(wid_height) - 3;
+            row_width += segment.width();
         }
     }
+    rows.push(&line[start..]);
+    rows
+}
+
+/// Number of rows the lines take once [`wrap`]ped.
+pub fn wrapped_height<'a>(lines: impl IntoIterator<Item = &'a str>, width: u16) -> usize {
+    lines.into_iter().map(|line| wrap(line, width).len()).sum()
+}
 
-    fn cursor_upper_bound(&self, wid_height: usize) -> usize {
-        wid_height.min(self.list.len())
+/// Words of `line` with the whitespace following them and their byte
+/// offset. Leading whitespace is a word of its own.
+fn words(line: &str) -> Vec<(usize, &str)> {
+    let mut starts = vec![0];
+    let mut after_space = false;
+    for (i, c) in line.char_indices() {
+        if after_space && !c.is_whitespace() {
+            starts.push(i);
+        }
+        after_space = c.is_whitespace();
     }
+    starts.push(line.len());
+    starts
+        .windows(2)
+        .filter(|w| w[0] < w[1])
+        .map(|w| (w[0], &line[w[0]..w[1]]))
+        .collect()
+}
 
-    pub fn window(&self, wid_height: usize) -> &[T] {
-        self.list
-            .windows(self.cursor_upper_bound(wid_height))
-            .nth(self.nth_window)
-            .unwrap_or(&[])
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scrolls_keeping_a_margin() {
+        let mut p = Paginate::new((0..20).collect::<Vec<_>>());
+        for _ in 0..6 {
+            p.next_elem(10);
+        }
+        assert_eq!(p.window(10), &(0..10).collect::<Vec<_>>()[..]);
+        p.next_elem(10);
+        assert_eq!(p.hovered(), Some(&7));
+        assert_eq!(p.window(10)[0], 1);
+        p.last_elem(10);
+        assert_eq!(p.window(10)[0], 10);
+        assert!(!p.next_elem(10));
+        p.first_elem(10);
+        assert_eq!((p.position(), p.window(10)[0]), (0, 0));
     }
-    pub fn hovered(&self) -> Option<&T> {
-        self.hovered.as_ref()
+
+    #[test]
+    fn test_pages() {
+        let mut p = Paginate::new((0..25).collect::<Vec<_>>());
+        p.page_down(10);
+        assert_eq!(p.hovered(), Some(&10));
+        // the margin keeps three rows above the hovered one
+        assert_eq!(p.window(10)[0], 7);
+        p.page_down(10);
+        p.page_down(10);
+        assert_eq!(p.hovered(), Some(&24));
+        assert_eq!(p.window(10)[0], 15);
+        p.half_page_up(10);
+        assert_eq!(p.hovered(), Some(&19));
+        p.page_up(10);
+        p.page_up(10);
+        assert_eq!((p.position(), p.window(10)[0]), (0, 0));
+    }
+
+    #[test]
+    fn test_jump_and_resize() {
+        let mut p = Paginate::new((0..100).collect::<Vec<_>>());
+        assert!(p.jump_to(50, 10));
+        assert!(!p.jump_to(100, 10));
+        assert!(p.window(10).contains(&50));
+        // a smaller window still shows the hovered element
+        assert!(p.window(4).contains(&50));
+        p.resize(4);
+        assert!(p.window(4).contains(&50));
+        assert!(p.prev_elem(4));
+        assert!(p.window(4).contains(&49));
+        assert_eq!(p.window(200).len(), 100);
+        assert!(p.scrollbar(200).is_none());
+        assert!(p.scrollbar(10).is_some());
+    }
+
+    #[test]
+    fn test_mouse() {
+        let mut p = Paginate::new((0..30).collect::<Vec<_>>());
+        p.page_down(10);
+        let top = p.window(10)[0];
+        assert!(p.hover_row(1, 10));
+        assert_eq!(p.hovered(), Some(&(top + 1)));
+        assert!(!p.hover_row(10, 10));
+        assert!(p.scroll(3, 10));
+        assert_eq!(p.hovered(), Some(&(top + 4)));
+        // rows below the end of the list hover nothing
+        let mut short = Paginate::new(vec![1, 2]);
+        assert!(!short.hover_row(5, 10));
+        assert!(!Paginate::<u32>::new(vec![]).scroll(3, 10));
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("", 10), [""]);
+        assert_eq!(wrap("two sum of an array", 10), ["two sum of ", "an array"]);
+        // indentation is kept and long words split
+        assert_eq!(wrap("    abcdefgh", 6), ["    ", "abcdef", "gh"]);
+        assert_eq!(wrap("数组求和", 5), ["数组", "求和"]);
+        assert_eq!(wrapped_height(["two sum of an array", ""], 10), 3);
+    }
+
+    #[test]
+    fn test_empty_list() {
+        let p = Paginate::<u32>::new(vec![]);
+        assert!(p.window(10).is_empty());
+        assert_eq!(p.hovered(), None);
     }
 }
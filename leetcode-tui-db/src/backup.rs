@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+use native_db::Database;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::errors::{DBResult, DbErr};
+use crate::models::{
+    list::DbList, note::DbNote, question::DbQuestion, review::DbReview, submission::DbSubmission,
+    topic::DbTopic, upsert,
+};
+
+/// Version of the backup format, bumped whenever a table or a column changes.
+pub const BACKUP_VERSION: u32 = 1;
+
+const CSV_VERSION_FILE: &str = "version.txt";
+
+/// Every table of the database holding data that cannot be fetched again
+/// from leetcode, plus the questions and topics with their statuses.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct Backup {
+    pub version: u32,
+    pub questions: Vec<DbQuestion>,
+    pub topics: Vec<DbTopic>,
+    pub notes: Vec<DbNote>,
+    pub lists: Vec<DbList>,
+    pub submissions: Vec<DbSubmission>,
+    pub reviews: Vec<DbReview>,
+}
+
+/// CSV row of a question, topics are joined by spaces as CSV has no lists.
+#[derive(Serialize, Deserialize)]
+struct QuestionRow {
+    id: u32,
+    title: String,
+    title_slug: String,
+    difficulty: String,
+    paid_only: bool,
+    status: Option<String>,
+    topics: String,
+    #[serde(default)]
+    ac_rate: f64,
+    #[serde(default)]
+    has_solution: bool,
+}
+
+/// CSV row of a list entry, one row per question in list order.
+#[derive(Serialize, Deserialize)]
+struct ListRow {
+    name: String,
+    question_id: u32,
+}
+
+impl Backup {
+    /// Reads every table in a single read transaction.
+    pub fn export<'a>(db: &'a Database<'a>) -> DBResult<Self> {
+        let r = db.r_transaction()?;
+        let scan = r.scan();
+        let backup = Self {
+            version: BACKUP_VERSION,
+            questions: scan.primary::<DbQuestion>()?.all().collect(),
+            topics: scan.primary::<DbTopic>()?.all().collect(),
+            notes: scan.primary::<DbNote>()?.all().collect(),
+            lists: scan.primary::<DbList>()?.all().collect(),
+            submissions: scan.primary::<DbSubmission>()?.all().collect(),
+            reviews: scan.primary::<DbReview>()?.all().collect(),
+        };
+        Ok(backup)
+    }
+
+    /// Restores the backup in a single transaction. Rows already in the
+    /// database are replaced, rows missing from the backup are kept.
+    pub fn import<'a>(&self, db: &'a Database<'a>) -> DBResult<()> {
+        if self.version > BACKUP_VERSION {
+            return Err(DbErr::UnsupportedBackupVersion(
+                self.version,
+                BACKUP_VERSION,
+            ));
+        }
+        let rw = db.rw_transaction()?;
+        // saving the questions also rebuilds the topic mappings and stats
+        DbQuestion::save_in(&self.questions, &rw)?;
+        for topic in self.topics.iter() {
+            upsert(&rw, topic.clone())?;
+        }
+        for note in self.notes.iter() {
+            upsert(&rw, note.clone())?;
+        }
+        for list in self.lists.iter() {
+            upsert(&rw, list.clone())?;
+        }
+        for submission in self.submissions.iter() {
+            upsert(&rw, submission.clone())?;
+        }
+        for review in self.reviews.iter() {
+            upsert(&rw, review.clone())?;
+        }
+        rw.commit()?;
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> DBResult<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> DBResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Writes one CSV file per table into `dir`, along with a `version.txt`.
+    pub fn write_csv(&self, dir: &Path) -> DBResult<()> {
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(dir.join(CSV_VERSION_FILE), format!("{}\n", self.version))?;
+        write_rows(
+            &dir.join("questions.csv"),
+            self.questions.iter().map(|q| QuestionRow {
+                id: q.id,
+                title: q.title.clone(),
+                title_slug: q.title_slug.clone(),
+                difficulty: q.difficulty.clone(),
+                paid_only: q.paid_only,
+                status: q.status.clone(),
+                topics: q
+                    .topics
+                    .iter()
+                    .map(|t| t.slug.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                ac_rate: q.ac_rate,
+                has_solution: q.has_solution,
+            }),
+        )?;
+        write_rows(&dir.join("topics.csv"), self.topics.iter())?;
+        write_rows(&dir.join("notes.csv"), self.notes.iter())?;
+        write_rows(
+            &dir.join("lists.csv"),
+            self.lists.iter().flat_map(|l| {
+                l.question_ids.iter().map(|id| ListRow {
+                    name: l.name.clone(),
+                    question_id: *id,
+                })
+            }),
+        )?;
+        write_rows(&dir.join("submissions.csv"), self.submissions.iter())?;
+        write_rows(&dir.join("reviews.csv"), self.reviews.iter())?;
+        Ok(())
+    }
+
+    /// Reads a backup written by [`Backup::write_csv`].
+    pub fn read_csv(dir: &Path) -> DBResult<Self> {
+        let version = std::fs::read_to_string(dir.join(CSV_VERSION_FILE))?;
+        let version = version
+            .trim()
+            .parse()
+            .map_err(|_| DbErr::InvalidBackupVersion(version.trim().into()))?;
+        let topics: Vec<DbTopic> = read_rows(&dir.join("topics.csv"))?;
+        let by_slug: HashMap<&str, &DbTopic> =
+            topics.iter().map(|t| (t.slug.as_str(), t)).collect();
+        let questions = read_rows::<QuestionRow>(&dir.join("questions.csv"))?
+            .into_iter()
+            .map(|row| {
+                let mut question = DbQuestion::new(
+                    row.id,
+                    &row.title,
+                    &row.title_slug,
+                    row.difficulty,
+                    row.paid_only,
+                    row.status,
+                );
+                question.ac_rate = row.ac_rate;
+                question.has_solution = row.has_solution;
+                question.topics = row
+                    .topics
+                    .split_whitespace()
+                    .map(|slug| {
+                        by_slug
+                            .get(slug)
+                            .map(|t| (*t).clone())
+                            .unwrap_or_else(|| DbTopic::new(slug))
+                    })
+                    .collect();
+                question
+            })
+            .collect();
+        let mut lists: Vec<DbList> = vec![];
+        for row in read_rows::<ListRow>(&dir.join("lists.csv"))? {
+            match lists.iter_mut().find(|l| l.name == row.name) {
+                Some(list) => list.question_ids.push(row.question_id),
+                None => {
+                    let mut list = DbList::new(&row.name);
+                    list.question_ids.push(row.question_id);
+                    lists.push(list);
+                }
+            }
+        }
+        Ok(Self {
+            version,
+            questions,
+            notes: read_rows(&dir.join("notes.csv"))?,
+            lists,
+            submissions: read_rows(&dir.join("submissions.csv"))?,
+            reviews: read_rows(&dir.join("reviews.csv"))?,
+            topics,
+        })
+    }
+}
+
+fn write_rows<T: Serialize>(path: &Path, rows: impl Iterator<Item = T>) -> DBResult<()> {
+    let mut writer = csv::Writer::from_path(path)?;
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn read_rows<T: DeserializeOwned>(path: &Path) -> DBResult<Vec<T>> {
+    let rows = csv::Reader::from_reader(File::open(path)?)
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+    Ok(rows)
+}
@@ -0,0 +1,95 @@
+use native_db::transaction::RTransaction;
+use native_db::Database;
+
+use crate::errors::DBResult;
+use crate::models::question::{DbQuestion, DbQuestionKey};
+
+/// Prefix of the slugs of the pseudo-topics showing a [`CuratedList`].
+pub const CURATED_PREFIX: &str = "curated:";
+
+/// A well known study list shipped with the app, stored as question title
+/// slugs in study order.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CuratedList {
+    pub slug: &'static str,
+    pub name: &'static str,
+    data: &'static str,
+}
+
+pub const CURATED_LISTS: [CuratedList; 3] = [
+    CuratedList {
+        slug: "blind-75",
+        name: "Blind 75",
+        data: include_str!("../data/blind75.txt"),
+    },
+    CuratedList {
+        slug: "neetcode-150",
+        name: "NeetCode 150",
+        data: include_str!("../data/neetcode150.txt"),
+    },
+    CuratedList {
+        slug: "grind-169",
+        name: "Grind 169",
+        data: include_str!("../data/grind169.txt"),
+    },
+];
+
+/// A curated list resolved against the questions in the database.
+#[derive(Debug, Default, Clone)]
+pub struct ResolvedList {
+    /// Ids of the questions found, in list order.
+    pub question_ids: Vec<u32>,
+    /// Title slugs with no matching question in the database.
+    pub missing: Vec<&'static str>,
+}
+
+impl CuratedList {
+    pub fn by_slug(slug: &str) -> Option<&'static Self> {
+        CURATED_LISTS.iter().find(|l| l.slug == slug)
+    }
+
+    /// Title slugs of the questions, blank lines and `#` comments skipped.
+    pub fn title_slugs(&self) -> impl Iterator<Item = &'static str> {
+        self.data
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+    }
+
+    /// Resolves every curated list in a single read transaction.
+    pub fn resolve_all<'a>(
+        db: &'a Database<'a>,
+    ) -> DBResult<Vec<(&'static CuratedList, ResolvedList)>> {
+        let r = db.r_transaction()?;
+        CURATED_LISTS
+            .iter()
+            .map(|l| Ok((l, l.resolve_in(&r)?)))
+            .collect()
+    }
+
+    /// Looks each title slug up in the title slug index.
+    pub(crate) fn resolve_in(&self, r: &RTransaction) -> DBResult<ResolvedList> {
+        let mut resolved = ResolvedList::default();
+        for slug in self.title_slugs() {
+            match question_id(slug, r)? {
+                Some(id) => resolved.question_ids.push(id),
+                None => resolved.missing.push(slug),
+            }
+        }
+        Ok(resolved)
+    }
+}
+
+/// Id of the question with the title slug, the highest one when leetcode
+/// renumbered it.
+fn question_id(slug: &str, r: &RTransaction) -> DBResult<Option<u32>> {
+    let id = r
+        .scan()
+        .secondary::<DbQuestion>(DbQuestionKey::title_slug)?
+        .start_with(slug.to_string())
+        // the index is searched by prefix
+        .filter(|q: &DbQuestion| q.title_slug == slug)
+        .map(|q| q.id)
+        .max();
+    Ok(id)
+}
@@ -19,6 +19,27 @@ pub enum DbErr {
 
     #[error("Topic not found: {0}")]
     TopicsNotFoundInDb(String),
+
+    #[error("List not found: {0}")]
+    ListNotFoundInDb(String),
+
+    #[error("Invalid question id in list: {0}")]
+    InvalidListEntry(String),
+
+    #[error("JsonError: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("CsvError: {0}")]
+    CsvError(#[from] csv::Error),
+
+    #[error("IoError: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Invalid backup version {0:?} in version.txt")]
+    InvalidBackupVersion(String),
+
+    #[error("Unsupported backup version {0}, expected at most {1}")]
+    UnsupportedBackupVersion(u32, u32),
 }
 
 pub type DBResult<T> = Result<T, DbErr>;
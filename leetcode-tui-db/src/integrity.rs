@@ -0,0 +1,164 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use native_db::transaction::RTransaction;
+use native_db::Database;
+use native_model::Model;
+
+use crate::errors::DBResult;
+use crate::models::{
+    list::DbList, note::DbNote, question::DbQuestion, review::DbReview, save_mappings,
+    submission::DbSubmission, topic::DbTopic, topic_stats::DbTopicStats, upsert, QuestionTopicMap,
+    TopicQuestionMap,
+};
+
+/// Row count and native_model id and version of a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableInfo {
+    pub name: &'static str,
+    pub model_id: u32,
+    pub model_version: u32,
+    pub rows: u64,
+}
+
+impl TableInfo {
+    fn of<T: Model + native_db::Input>(name: &'static str, r: &RTransaction) -> DBResult<Self> {
+        Ok(Self {
+            name,
+            model_id: T::native_model_id(),
+            model_version: T::native_model_version(),
+            rows: r.len().primary::<T>()?,
+        })
+    }
+}
+
+/// Result of [`check`]. Orphan and stale mappings make topics fail to load
+/// or show questions that are no longer tagged with them.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IntegrityReport {
+    pub tables: Vec<TableInfo>,
+    /// Ids of mappings pointing to a question missing from the questions table.
+    pub orphan_maps: Vec<String>,
+    /// Ids of mappings to a topic their question is no longer tagged with.
+    pub stale_maps: Vec<String>,
+    /// Question topics with no mapping in one or both directions.
+    pub missing_maps: usize,
+    /// Questions listing the same topic more than once.
+    pub duplicate_topics: Vec<u32>,
+    /// Title slugs shared by several question ids, e.g. after leetcode
+    /// renumbered a question. Not fixed by [`repair`].
+    pub duplicate_slugs: BTreeMap<String, Vec<u32>>,
+}
+
+impl IntegrityReport {
+    /// Whether [`repair`] has nothing left to fix.
+    pub fn is_ok(&self) -> bool {
+        self.orphan_maps.is_empty()
+            && self.stale_maps.is_empty()
+            && self.missing_maps == 0
+            && self.duplicate_topics.is_empty()
+    }
+}
+
+/// Scans the topic mappings against the questions table in a single read
+/// transaction.
+pub fn check<'a>(db: &'a Database<'a>) -> DBResult<IntegrityReport> {
+    let r = db.r_transaction()?;
+    let questions: HashMap<u32, DbQuestion> = r
+        .scan()
+        .primary::<DbQuestion>()?
+        .all()
+        .map(|q: DbQuestion| (q.id, q))
+        .collect();
+    let mut report = IntegrityReport {
+        tables: vec![
+            TableInfo::of::<DbQuestion>("questions", &r)?,
+            TableInfo::of::<DbTopic>("topics", &r)?,
+            TableInfo::of::<TopicQuestionMap>("topic_question_map", &r)?,
+            TableInfo::of::<QuestionTopicMap>("question_topic_map", &r)?,
+            TableInfo::of::<DbTopicStats>("topic_stats", &r)?,
+            TableInfo::of::<DbSubmission>("submissions", &r)?,
+            TableInfo::of::<DbNote>("notes", &r)?,
+            TableInfo::of::<DbReview>("reviews", &r)?,
+            TableInfo::of::<DbList>("lists", &r)?,
+        ],
+        ..Default::default()
+    };
+
+    let mut mapped = HashSet::new();
+    let mut maps: Vec<_> = r
+        .scan()
+        .primary::<TopicQuestionMap>()?
+        .all()
+        .map(|m: TopicQuestionMap| (m.id, m.question_id, m.topic_id, "tq"))
+        .collect();
+    let question_topic_maps: Vec<_> = r
+        .scan()
+        .primary::<QuestionTopicMap>()?
+        .all()
+        .map(|m: QuestionTopicMap| (m.id, m.question_id, m.topic_id, "qt"))
+        .collect();
+    maps.extend(question_topic_maps);
+    for (id, question_id, topic_id, direction) in maps {
+        match questions.get(&question_id) {
+            None => report.orphan_maps.push(id),
+            Some(q) if !q.topics.iter().any(|t| t.slug == topic_id) => report.stale_maps.push(id),
+            Some(_) => {
+                mapped.insert((direction, question_id, topic_id));
+            }
+        }
+    }
+
+    let mut by_slug: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+    let mut ids: Vec<&u32> = questions.keys().collect();
+    ids.sort();
+    for question in ids.into_iter().map(|id| &questions[id]) {
+        by_slug
+            .entry(question.title_slug.clone())
+            .or_default()
+            .push(question.id);
+        let mut seen = HashSet::new();
+        for topic in question.topics.iter() {
+            if !seen.insert(&topic.slug) {
+                report.duplicate_topics.push(question.id);
+                continue;
+            }
+            if ["tq", "qt"]
+                .iter()
+                .any(|d| !mapped.contains(&(*d, question.id, topic.slug.clone())))
+            {
+                report.missing_maps += 1;
+            }
+        }
+    }
+    report.duplicate_slugs = by_slug
+        .into_iter()
+        .filter(|(_, ids)| ids.len() > 1)
+        .collect();
+    report.duplicate_topics.dedup();
+    Ok(report)
+}
+
+/// Rebuilds both topic mappings from the `topics` field of the questions,
+/// dropping repeated topics, then recomputes the topic stats. Returns the
+/// report of a check run after the repair.
+pub fn repair<'a>(db: &'a Database<'a>) -> DBResult<IntegrityReport> {
+    let rw = db.rw_transaction()?;
+    rw.drain().primary::<TopicQuestionMap>()?;
+    rw.drain().primary::<QuestionTopicMap>()?;
+    let questions: Vec<DbQuestion> = rw.scan().primary::<DbQuestion>()?.all().collect();
+    for old in questions {
+        let mut question = old.clone();
+        let mut seen = HashSet::new();
+        question.topics.retain(|t| seen.insert(t.slug.clone()));
+        save_mappings(None, &question, &rw)?;
+        for topic in question.topics.iter() {
+            upsert(&rw, topic.clone())?;
+        }
+        if question != old {
+            rw.update(old, question)?;
+        }
+    }
+    rw.commit()?;
+    DbTopicStats::rebuild(db)?;
+    check(db)
+}
@@ -1,8 +1,23 @@
+pub mod backup;
+pub mod curated;
 pub mod errors;
+pub mod integrity;
 pub mod models;
+pub mod query;
 use leetcode_core as api;
-pub use models::{question::DbQuestion, topic::DbTopic};
+pub use backup::{Backup, BACKUP_VERSION};
+pub use curated::{CuratedList, ResolvedList, CURATED_LISTS, CURATED_PREFIX};
+pub use models::{
+    list::{DbList, LIST_PREFIX},
+    note::DbNote,
+    question::DbQuestion,
+    review::{DbReview, Grade, DUE_FOR_REVIEW},
+    submission::{DbSubmission, SubmissionKind},
+    topic::{DbTopic, TopicSetOp},
+    topic_stats::DbTopicStats,
+};
 use models::{QuestionTopicMap, TopicQuestionMap};
+pub use query::QuestionQuery;
 use native_db::DatabaseBuilder;
 use leetcode_tui_shared::RoCell;
 
@@ -13,6 +28,11 @@ pub fn define_schema(db_builder: &mut DatabaseBuilder) -> errors::DBResult<()> {
     db_builder.define::<DbTopic>()?;
     db_builder.define::<QuestionTopicMap>()?;
     db_builder.define::<TopicQuestionMap>()?;
+    db_builder.define::<DbTopicStats>()?;
+    db_builder.define::<DbSubmission>()?;
+    db_builder.define::<DbNote>()?;
+    db_builder.define::<DbReview>()?;
+    db_builder.define::<DbList>()?;
     Ok(())
 }
 
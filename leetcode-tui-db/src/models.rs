@@ -1,95 +1,120 @@
+use native_db::transaction::{RTransaction, RwTransaction};
 use native_db::*;
 use native_model::{native_model, Model};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::hash::Hash;
 
 use crate::errors::DBResult;
 
-use self::topic::DbTopic;
+pub mod list;
+pub mod note;
 pub mod question;
+pub mod review;
+pub mod submission;
 pub mod topic;
+pub mod topic_stats;
+
+/// Inserts `item`, or replaces the stored item with the same primary key so
+/// that its secondary keys are updated as well.
+pub(crate) fn upsert<T: Input + Clone>(rw: &RwTransaction, item: T) -> DBResult<()> {
+    match rw.get().primary::<T>(item.native_db_primary_key())? {
+        Some(old) => rw.update(old, item)?,
+        None => rw.insert(item)?,
+    }
+    Ok(())
+}
+
+/// Removes the item with the given primary key, if any.
+pub(crate) fn remove<T: Input>(rw: &RwTransaction, key: impl InnerKeyValue) -> DBResult<()> {
+    if let Some(old) = rw.get().primary::<T>(key)? {
+        rw.remove(old)?;
+    }
+    Ok(())
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
-#[native_model(id = 3, version = 1)]
+#[native_model(id = 3, version = 2)]
 #[native_db]
 pub(crate) struct TopicQuestionMap {
     #[primary_key]
-    id: String,
+    pub(crate) id: String,
     #[secondary_key]
-    topic_id: String,
-    question_id: u32,
+    pub(crate) topic_id: String,
+    pub(crate) question_id: u32,
 }
 
 impl TopicQuestionMap {
     fn new(topic_id: &str, question_id: u32) -> Self {
         Self {
-            id: format!("{topic_id}_{question_id}"),
+            id: Self::id(topic_id, question_id),
             topic_id: topic_id.to_string(),
             question_id,
         }
     }
 
-    pub(crate) fn save_mapping<'a>(question: &question::DbQuestion, db: &Database) -> DBResult<()> {
-        for topic in question.get_topics() {
-            let topic_question_mapping = Self::new(&topic.slug, question.id);
-            topic_question_mapping.save_to_db(db)?;
-        }
-        Ok(())
+    fn id(topic_id: &str, question_id: u32) -> String {
+        format!("{topic_id}_{question_id}")
     }
 
-    fn save_to_db<'a>(&self, db: &Database) -> DBResult<()> {
-        let rw_trans = db.rw_transaction()?;
-        rw_trans.insert(self.clone())?;
-        rw_trans.commit()?;
-        Ok(())
-    }
-
-    pub(crate) fn get_all_question_by_topic(topic: &DbTopic, db: &Database) -> DBResult<Vec<u32>> {
-        let trans = db.r_transaction()?;
-        let mut quests = vec![];
-        for tq_map in trans
+    pub(crate) fn get_question_ids_by_topic_slug(
+        slug: &str,
+        trans: &RTransaction,
+    ) -> DBResult<BTreeSet<u32>> {
+        // secondary keys are matched by prefix, "string" would also match "string-matching"
+        Ok(trans
             .scan()
             .secondary::<Self>(TopicQuestionMapKey::topic_id)?
-            .start_with(topic.slug.to_string())
-        {
-            quests.push(tq_map.question_id);
-        }
-        Ok(quests)
+            .start_with(slug.to_string())
+            .filter(|tq_map: &Self| tq_map.topic_id == slug)
+            .map(|tq_map| tq_map.question_id)
+            .collect())
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
-#[native_model(id = 4, version = 1)]
+#[native_model(id = 4, version = 2)]
 #[native_db]
 pub(crate) struct QuestionTopicMap {
     #[primary_key]
-    id: String,
+    pub(crate) id: String,
     #[secondary_key]
-    question_id: u32,
-    topic_id: String,
+    pub(crate) question_id: u32,
+    pub(crate) topic_id: String,
 }
 
 impl QuestionTopicMap {
     fn new(question_id: u32, topic_id: &str) -> Self {
         Self {
-            id: format!("{question_id}_{topic_id}"),
+            id: Self::id(question_id, topic_id),
             question_id,
             topic_id: topic_id.to_string(),
         }
     }
 
-    pub(crate) fn save_mapping<'a>(question: &question::DbQuestion, db: &Database) -> DBResult<()> {
-        for topic in question.get_topics() {
-            let question_topic_mapping = Self::new(question.id, &topic.slug);
-            question_topic_mapping.save_to_db(db)?;
-        }
-        Ok(())
+    fn id(question_id: u32, topic_id: &str) -> String {
+        format!("{question_id}_{topic_id}")
     }
+}
 
-    fn save_to_db<'a>(&self, db: &Database) -> DBResult<()> {
-        let rw_trans = db.rw_transaction()?;
-        rw_trans.insert(self.clone())?;
-        rw_trans.commit()?;
-        Ok(())
+/// Writes the topic <-> question mappings of `new` in both directions,
+/// dropping the ones of topics `old` had but `new` no longer has.
+pub(crate) fn save_mappings(
+    old: Option<&question::DbQuestion>,
+    new: &question::DbQuestion,
+    rw: &RwTransaction,
+) -> DBResult<()> {
+    let stale = old
+        .into_iter()
+        .flat_map(|old| old.get_topics())
+        .filter(|t| !new.get_topics().iter().any(|n| n.slug == t.slug));
+    for topic in stale {
+        remove::<TopicQuestionMap>(rw, TopicQuestionMap::id(&topic.slug, new.id))?;
+        remove::<QuestionTopicMap>(rw, QuestionTopicMap::id(new.id, &topic.slug))?;
+    }
+    for topic in new.get_topics() {
+        upsert(rw, TopicQuestionMap::new(&topic.slug, new.id))?;
+        upsert(rw, QuestionTopicMap::new(new.id, &topic.slug))?;
     }
+    Ok(())
 }
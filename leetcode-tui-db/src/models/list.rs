@@ -0,0 +1,131 @@
+use native_db::transaction::RTransaction;
+
+use crate::errors::DbErr;
+
+use super::*;
+
+/// Prefix of the slugs of the pseudo-topics showing a [`DbList`].
+pub const LIST_PREFIX: &str = "list:";
+
+/// A named, ordered list of questions curated by the user.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[native_model(id = 9, version = 1)]
+#[native_db]
+pub struct DbList {
+    #[primary_key]
+    pub name: String,
+    pub question_ids: Vec<u32>,
+}
+
+impl DbList {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            question_ids: vec![],
+        }
+    }
+
+    pub fn fetch_all<'a>(db: &'a Database<'a>) -> DBResult<Vec<Self>> {
+        let r = db.r_transaction()?;
+        let lists = r.scan().primary::<Self>()?.all().collect();
+        Ok(lists)
+    }
+
+    pub fn get<'a>(name: &str, db: &'a Database<'a>) -> DBResult<Self> {
+        let r = db.r_transaction()?;
+        Self::get_in(name, &r)
+    }
+
+    pub(crate) fn get_in(name: &str, r: &RTransaction) -> DBResult<Self> {
+        r.get()
+            .primary::<Self>(name)?
+            .ok_or(DbErr::ListNotFoundInDb(name.into()))
+    }
+
+    /// Saves the list, replacing a list with the same name.
+    pub fn save_to_db<'a>(&self, db: &'a Database<'a>) -> DBResult<()> {
+        let rw = db.rw_transaction()?;
+        upsert(&rw, self.clone())?;
+        rw.commit()?;
+        Ok(())
+    }
+
+    pub fn delete<'a>(name: &str, db: &'a Database<'a>) -> DBResult<()> {
+        let rw = db.rw_transaction()?;
+        remove::<Self>(&rw, name)?;
+        rw.commit()?;
+        Ok(())
+    }
+
+    /// Appends the question to the list, creating the list if needed.
+    /// Returns false when the question was already in the list.
+    pub fn add_question<'a>(name: &str, question_id: u32, db: &'a Database<'a>) -> DBResult<bool> {
+        let rw = db.rw_transaction()?;
+        let mut list = rw
+            .get()
+            .primary::<Self>(name)?
+            .unwrap_or_else(|| Self::new(name));
+        if list.question_ids.contains(&question_id) {
+            return Ok(false);
+        }
+        list.question_ids.push(question_id);
+        upsert(&rw, list)?;
+        rw.commit()?;
+        Ok(true)
+    }
+
+    /// Returns false when the question was not in the list.
+    pub fn remove_question<'a>(
+        name: &str,
+        question_id: u32,
+        db: &'a Database<'a>,
+    ) -> DBResult<bool> {
+        let rw = db.rw_transaction()?;
+        let mut list = rw
+            .get()
+            .primary::<Self>(name)?
+            .ok_or(DbErr::ListNotFoundInDb(name.into()))?;
+        let len = list.question_ids.len();
+        list.question_ids.retain(|id| *id != question_id);
+        if list.question_ids.len() == len {
+            return Ok(false);
+        }
+        upsert(&rw, list)?;
+        rw.commit()?;
+        Ok(true)
+    }
+
+    /// One question id per line. Blank lines and lines starting with `#` are
+    /// ignored on import.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("# {}\n", self.name);
+        for id in self.question_ids.iter() {
+            text.push_str(&format!("{id}\n"));
+        }
+        text
+    }
+
+    pub fn from_text(name: &str, text: &str) -> DBResult<Self> {
+        let mut list = Self::new(name);
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let id = line
+                .parse()
+                .map_err(|_| DbErr::InvalidListEntry(line.into()))?;
+            if !list.question_ids.contains(&id) {
+                list.question_ids.push(id);
+            }
+        }
+        Ok(list)
+    }
+
+    pub fn to_json(&self) -> DBResult<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> DBResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use native_db::transaction::RTransaction;
+
+use super::*;
+
+/// Personal notes on a question, kept in markdown.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[native_model(id = 7, version = 1)]
+#[native_db]
+pub struct DbNote {
+    #[primary_key]
+    pub question_id: u32,
+    pub content: String,
+    /// Seconds since the unix epoch.
+    pub updated_at: u64,
+}
+
+impl DbNote {
+    pub fn get<'a>(question_id: u32, db: &'a Database<'a>) -> DBResult<Option<Self>> {
+        let r = db.r_transaction()?;
+        Ok(r.get().primary::<Self>(question_id)?)
+    }
+
+    /// Saves the note of a question, removing it when `content` is blank.
+    pub fn save<'a>(question_id: u32, content: &str, db: &'a Database<'a>) -> DBResult<()> {
+        let rw = db.rw_transaction()?;
+        if content.trim().is_empty() {
+            remove::<Self>(&rw, question_id)?;
+        } else {
+            let updated_at = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+            upsert(
+                &rw,
+                Self {
+                    question_id,
+                    content: content.into(),
+                    updated_at,
+                },
+            )?;
+        }
+        rw.commit()?;
+        Ok(())
+    }
+
+    pub(crate) fn question_ids(r: &RTransaction) -> DBResult<HashSet<u32>> {
+        Ok(r.scan()
+            .primary::<Self>()?
+            .all()
+            .map(|n| n.question_id)
+            .collect())
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use super::{topic::DbTopic, *};
+use super::{topic::DbTopic, topic_stats::DbTopicStats, *};
 
 use crate::{
     api::types::problemset_question_list::Question,
@@ -8,17 +8,30 @@ use crate::{
 };
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
-#[native_model(id = 1, version = 1)]
+#[native_model(id = 1, version = 2)]
 #[native_db]
 pub struct DbQuestion {
     #[primary_key]
     pub id: u32,
     pub title: String,
+    #[secondary_key]
     pub title_slug: String,
+    #[secondary_key]
     pub difficulty: String,
     pub paid_only: bool,
+    #[secondary_key(optional)]
     pub status: Option<String>,
     pub topics: Vec<DbTopic>,
+    /// Percentage of accepted submissions.
+    #[serde(default)]
+    pub ac_rate: f64,
+    /// Whether the question has an official solution article.
+    #[serde(default)]
+    pub has_solution: bool,
+    /// Whether a [`DbNote`](super::note::DbNote) exists, filled in by
+    /// [`QuestionQuery`](crate::QuestionQuery).
+    #[serde(skip)]
+    pub has_note: bool,
 }
 
 impl Display for DbQuestion {
@@ -32,6 +45,7 @@ impl Display for DbQuestion {
         } else {
             "🏃"
         });
+        w.push_str(if self.has_note { "📝" } else { "  " });
         w.push_str(self.title.as_str());
         write!(f, "{: >4}{w}", self.id)
     }
@@ -65,14 +79,10 @@ impl TryFrom<Question> for DbQuestion {
             value.paid_only,
             value.status,
         );
+        db_quest.ac_rate = value.ac_rate.unwrap_or_default();
+        db_quest.has_solution = value.has_solution.unwrap_or_default();
         if let Some(tts) = value.topic_tags {
-            if !tts.is_empty() {
-                for topic in tts {
-                    db_quest.add_topic(topic.slug.as_str());
-                }
-            } else {
-                db_quest.add_topic("unknown");
-            }
+            db_quest.topics.extend(tts.into_iter().map(DbTopic::from));
         }
         Ok(db_quest)
     }
@@ -95,13 +105,12 @@ impl DbQuestion {
             difficulty,
             paid_only,
             status,
+            ac_rate: 0.0,
+            has_solution: false,
+            has_note: false,
         }
     }
 
-    fn add_topic(&mut self, slug: &str) {
-        self.topics.push(DbTopic::new(slug))
-    }
-
     pub fn mark_accepted<'a>(&mut self, db: &'a Database<'a>) -> DBResult<Option<Vec<Self>>> {
         if self.status.is_none() || self.status == Some("notac".into()) {
             self.status = Some("ac".into());
@@ -120,7 +129,11 @@ impl DbQuestion {
 
     fn update_in_db<'a>(&self, db: &'a Database<'a>) -> DBResult<Vec<Self>> {
         let rw = db.rw_transaction()?;
-        let old = Self::get_question_by_id(db, self.id)?;
+        let old = rw
+            .get()
+            .primary::<Self>(self.id)?
+            .ok_or(DbErr::QuestionsNotFoundInDb(self.id.to_string()))?;
+        DbTopicStats::record_change(&rw, Some(&old), Some(self))?;
         rw.update(old, self.clone())?;
         rw.commit()?;
         Ok(vec![self.clone()])
@@ -128,8 +141,7 @@ impl DbQuestion {
 
     pub fn get_total_questions<'a>(db: &'a Database<'a>) -> DBResult<usize> {
         let r = db.r_transaction()?;
-        let x = r.scan().primary::<Self>()?;
-        Ok(x.all().count())
+        Ok(r.len().primary::<Self>()? as usize)
     }
 
     pub fn get_question_by_id<'a>(db: &'a Database<'a>, id: u32) -> DBResult<Self> {
@@ -142,13 +154,6 @@ impl DbQuestion {
         Ok(x)
     }
 
-    fn save_all_topics<'a>(&mut self, db: &'a Database<'a>) -> DBResult<()> {
-        for topic in self.topics.iter() {
-            topic.save_to_db(db)?;
-        }
-        Ok(())
-    }
-
     // pub fn fetch_all_topics<'a>(&self, db: &'a Database<'a>) -> DBResult<Vec<DbTopic>> {
     //     let q_topic_map = QuestionTopicMap::get_all_topic_slug_by_question(self, db)?;
     //     let mut topics = vec![];
@@ -164,24 +169,33 @@ impl DbQuestion {
     }
 
     pub fn save_to_db<'a>(&mut self, db: &'a Database<'a>) -> DBResult<bool> {
-        // save Topic -> Question Mapping
-        TopicQuestionMap::save_mapping(self, db)?;
-
-        // save Question -> Topic Mapping
-        QuestionTopicMap::save_mapping(self, db)?;
-
-        // save DbTopics for the question
-        self.save_all_topics(db)?;
+        Self::save_many(std::slice::from_ref(self), db)?;
+        Ok(true)
+    }
 
-        // save question
-        save(self.clone(), db)?;
-        return Ok(true);
+    /// Saves `questions` together with their topics and topic mappings in a
+    /// single transaction. Questions already in the db are replaced, so a
+    /// re-sync can save the same page again.
+    pub fn save_many<'a>(questions: &[Self], db: &'a Database<'a>) -> DBResult<()> {
+        let rw = db.rw_transaction()?;
+        Self::save_in(questions, &rw)?;
+        rw.commit()?;
+        Ok(())
     }
-}
 
-fn save<'a, T: Input>(item: T, db: &'a Database<'a>) -> DBResult<()> {
-    let rw = db.rw_transaction()?;
-    rw.insert(item)?;
-    rw.commit()?;
-    Ok(())
+    pub(crate) fn save_in(questions: &[Self], rw: &RwTransaction) -> DBResult<()> {
+        for question in questions {
+            let old = rw.get().primary::<Self>(question.id)?;
+            save_mappings(old.as_ref(), question, rw)?;
+            for topic in question.get_topics() {
+                upsert(rw, topic.clone())?;
+            }
+            DbTopicStats::record_change(rw, old.as_ref(), Some(question))?;
+            match old {
+                Some(old) => rw.update(old, question.clone())?,
+                None => rw.insert(question.clone())?,
+            }
+        }
+        Ok(())
+    }
 }
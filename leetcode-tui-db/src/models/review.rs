@@ -0,0 +1,139 @@
+use std::collections::BTreeSet;
+
+use native_db::transaction::RTransaction;
+
+use super::*;
+
+/// Slug of the pseudo-topic listing the questions due for review.
+pub const DUE_FOR_REVIEW: &str = "due-for-review";
+
+/// Days since the unix epoch, counted in local time so the day flips at local
+/// midnight.
+pub fn today() -> u32 {
+    let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap_or_default();
+    (chrono::Local::now().date_naive() - epoch).num_days() as u32
+}
+
+/// How well a question was recalled when re-solving it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grade {
+    /// Could not solve it without help.
+    Again,
+    /// Solved it with serious effort.
+    Hard,
+    /// Solved it after some thought.
+    Good,
+    /// Solved it right away.
+    Easy,
+}
+
+impl Grade {
+    pub const ALL: [Grade; 4] = [Grade::Again, Grade::Hard, Grade::Good, Grade::Easy];
+
+    /// Response quality on SM-2's 0-5 scale.
+    fn quality(self) -> u32 {
+        match self {
+            Grade::Again => 1,
+            Grade::Hard => 3,
+            Grade::Good => 4,
+            Grade::Easy => 5,
+        }
+    }
+}
+
+impl std::fmt::Display for Grade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Grade::Again => write!(f, "Again - could not solve it without help"),
+            Grade::Hard => write!(f, "Hard - solved it with serious effort"),
+            Grade::Good => write!(f, "Good - solved it after some thought"),
+            Grade::Easy => write!(f, "Easy - solved it right away"),
+        }
+    }
+}
+
+/// SM-2 review schedule of a question.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[native_model(id = 8, version = 1)]
+#[native_db]
+pub struct DbReview {
+    #[primary_key]
+    pub question_id: u32,
+    pub ease: f32,
+    /// Days until the next review.
+    pub interval: u32,
+    /// Reviews in a row recalled with at least a `Hard` grade.
+    pub repetitions: u32,
+    /// Day of the next review, in days since the unix epoch.
+    #[secondary_key]
+    pub due: u32,
+}
+
+impl DbReview {
+    pub fn new(question_id: u32, today: u32) -> Self {
+        Self {
+            question_id,
+            ease: 2.5,
+            interval: 0,
+            repetitions: 0,
+            due: today,
+        }
+    }
+
+    /// Schedules the next review after a review graded `grade` on `today`.
+    pub fn review(&mut self, grade: Grade, today: u32) {
+        let q = grade.quality();
+        if q < 3 {
+            self.repetitions = 0;
+            self.interval = 1;
+        } else {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval as f32 * self.ease).round() as u32,
+            };
+            self.repetitions += 1;
+        }
+        let miss = (5 - q) as f32;
+        self.ease = (self.ease + 0.1 - miss * (0.08 + miss * 0.02)).max(1.3);
+        self.due = today + self.interval;
+    }
+
+    pub fn get<'a>(question_id: u32, db: &'a Database<'a>) -> DBResult<Option<Self>> {
+        let r = db.r_transaction()?;
+        Ok(r.get().primary::<Self>(question_id)?)
+    }
+
+    /// Records a review of the question graded `grade`, starting a schedule
+    /// for it if it had none.
+    pub fn record<'a>(
+        question_id: u32,
+        grade: Grade,
+        today: u32,
+        db: &'a Database<'a>,
+    ) -> DBResult<Self> {
+        let rw = db.rw_transaction()?;
+        let mut review = rw
+            .get()
+            .primary::<Self>(question_id)?
+            .unwrap_or_else(|| Self::new(question_id, today));
+        review.review(grade, today);
+        upsert(&rw, review.clone())?;
+        rw.commit()?;
+        Ok(review)
+    }
+
+    pub(crate) fn due_question_ids(today: u32, r: &RTransaction) -> DBResult<BTreeSet<u32>> {
+        Ok(r.scan()
+            .secondary::<Self>(DbReviewKey::due)?
+            .range(..today + 1)
+            .map(|review: Self| review.question_id)
+            .collect())
+    }
+
+    /// Number of questions due for review on `today`.
+    pub fn due_count<'a>(today: u32, db: &'a Database<'a>) -> DBResult<usize> {
+        let r = db.r_transaction()?;
+        Ok(Self::due_question_ids(today, &r)?.len())
+    }
+}
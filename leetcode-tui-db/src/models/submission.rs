@@ -0,0 +1,157 @@
+use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::api::types::run_submit_response::ParsedResponse;
+
+use super::*;
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SubmissionKind {
+    /// Run against the sample test cases.
+    Run,
+    /// Submitted against the full test suite.
+    Submit,
+}
+
+impl Display for SubmissionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionKind::Run => write!(f, "Run"),
+            SubmissionKind::Submit => write!(f, "Submit"),
+        }
+    }
+}
+
+/// A run or submit of a solution, recorded locally so the history of a
+/// question can be shown without the network.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[native_model(id = 6, version = 1)]
+#[native_db]
+pub struct DbSubmission {
+    #[primary_key]
+    id: String,
+    #[secondary_key]
+    pub question_id: u32,
+    pub language: String,
+    pub kind: SubmissionKind,
+    pub verdict: String,
+    pub runtime: Option<String>,
+    pub memory: Option<String>,
+    pub runtime_percentile: Option<f32>,
+    pub memory_percentile: Option<f32>,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// FNV-1a hash of the submitted code, tells apart submissions of the same
+    /// code from ones of changed code.
+    pub code_hash: u64,
+}
+
+/// 64 bit FNV-1a hash, stable across runs and platforms.
+pub fn code_hash(code: &str) -> u64 {
+    code.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+impl DbSubmission {
+    pub fn new(
+        question_id: u32,
+        language: &str,
+        kind: SubmissionKind,
+        code: &str,
+        response: &ParsedResponse,
+    ) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let code_hash = code_hash(code);
+        let mut submission = Self {
+            id: format!("{question_id}_{timestamp}_{kind}_{code_hash:x}"),
+            question_id,
+            language: language.into(),
+            kind,
+            verdict: Default::default(),
+            runtime: None,
+            memory: None,
+            runtime_percentile: None,
+            memory_percentile: None,
+            timestamp,
+            code_hash,
+        };
+        submission.set_result(response);
+        submission
+    }
+
+    fn set_result(&mut self, response: &ParsedResponse) {
+        self.verdict = match response {
+            ParsedResponse::Pending => "Pending",
+            ParsedResponse::CompileError(_) => "Compile Error",
+            ParsedResponse::RuntimeError(_) => "Runtime Error",
+            ParsedResponse::MemoryLimitExceeded(_) => "Memory Limit Exceeded",
+            ParsedResponse::OutputLimitExceed(_) => "Output Limit Exceeded",
+            ParsedResponse::TimeLimitExceeded(_) => "Time Limit Exceeded",
+            ParsedResponse::InternalError(_) => "Internal Error",
+            ParsedResponse::Unknown(_) => "Unknown",
+            ParsedResponse::TimeOut(_) => "Timeout",
+            ParsedResponse::RunAccepted(_) | ParsedResponse::SubmitAccepted(_) => "Accepted",
+            ParsedResponse::RunWrongAnswer(_) | ParsedResponse::SubmitWrongAnswer(_) => {
+                "Wrong Answer"
+            }
+        }
+        .into();
+
+        match response {
+            ParsedResponse::RunAccepted(r) => {
+                self.runtime = Some(r.status_runtime.clone());
+                self.memory = Some(r.memory.to_string());
+                self.runtime_percentile = r.runtime_percentile;
+                self.memory_percentile = r.memory_percentile;
+            }
+            ParsedResponse::RunWrongAnswer(r) => {
+                self.runtime = Some(r.status_runtime.clone());
+                self.memory = Some(r.memory.to_string());
+            }
+            ParsedResponse::SubmitAccepted(r) => {
+                self.runtime = Some(r.status_runtime.clone());
+                self.memory = Some(r.memory.to_string());
+                self.runtime_percentile = Some(r.runtime_percentile);
+                self.memory_percentile = Some(r.memory_percentile);
+            }
+            ParsedResponse::SubmitWrongAnswer(r) => {
+                self.runtime = Some(r.status_runtime.clone());
+                self.memory = Some(r.memory.to_string());
+            }
+            ParsedResponse::MemoryLimitExceeded(r) => self.memory = Some(r.memory.to_string()),
+            ParsedResponse::OutputLimitExceed(r) => self.memory = Some(r.memory.to_string()),
+            ParsedResponse::TimeLimitExceeded(r) => {
+                self.runtime = Some(format!("{} ms", r.elapsed_time))
+            }
+            _ => (),
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        self.verdict == "Accepted"
+    }
+
+    pub fn save_to_db<'a>(&self, db: &'a Database<'a>) -> DBResult<()> {
+        let rw = db.rw_transaction()?;
+        upsert(&rw, self.clone())?;
+        rw.commit()?;
+        Ok(())
+    }
+
+    /// Submissions of a question, most recent first.
+    pub fn fetch_for_question<'a>(question_id: u32, db: &'a Database<'a>) -> DBResult<Vec<Self>> {
+        let r = db.r_transaction()?;
+        let mut submissions: Vec<Self> = r
+            .scan()
+            .secondary::<Self>(DbSubmissionKey::question_id)?
+            .start_with(question_id)
+            .filter(|s: &Self| s.question_id == question_id)
+            .collect();
+        submissions.sort_by_key(|s| std::cmp::Reverse(s.timestamp));
+        Ok(submissions)
+    }
+}
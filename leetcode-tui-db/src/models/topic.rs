@@ -1,13 +1,60 @@
-use crate::errors::DBResult;
+use std::fmt::Display;
+
+use crate::api::types::problemset_question_list::TopicTag;
+use crate::curated::{CuratedList, CURATED_PREFIX};
+use crate::errors::{DBResult, DbErr};
+use crate::query::QuestionQuery;
 
 use super::{question::DbQuestion, *};
 
-#[native_model(id = 2, version = 1)]
+/// How the question sets of several selected topics are combined.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TopicSetOp {
+    /// Questions tagged with every selected topic.
+    #[default]
+    And,
+    /// Questions tagged with any of the selected topics.
+    Or,
+}
+
+impl TopicSetOp {
+    pub fn toggle(self) -> Self {
+        match self {
+            TopicSetOp::And => TopicSetOp::Or,
+            TopicSetOp::Or => TopicSetOp::And,
+        }
+    }
+}
+
+impl Display for TopicSetOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TopicSetOp::And => write!(f, "AND"),
+            TopicSetOp::Or => write!(f, "OR"),
+        }
+    }
+}
+
+#[native_model(id = 2, version = 2)]
 #[native_db]
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct DbTopic {
     #[primary_key]
     pub slug: String,
+    /// Display name, e.g. "Dynamic Programming".
+    pub name: String,
+    /// Leetcode's tag id, empty for pseudo-topics like `all`.
+    pub id: String,
+}
+
+impl From<TopicTag> for DbTopic {
+    fn from(value: TopicTag) -> Self {
+        Self {
+            slug: value.slug,
+            name: value.name,
+            id: value.id,
+        }
+    }
 }
 
 impl Hash for DbTopic {
@@ -17,44 +64,99 @@ impl Hash for DbTopic {
 }
 
 impl DbTopic {
+    /// Creates a topic named after its slug, "dynamic-programming" becomes
+    /// "Dynamic Programming".
     pub fn new(slug: &str) -> Self {
-        Self { slug: slug.into() }
+        let name = slug
+            .split('-')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        Self {
+            slug: slug.into(),
+            name,
+            id: Default::default(),
+        }
+    }
+
+    /// Pseudo-topic listing the questions due for review today.
+    pub fn due_for_review() -> Self {
+        Self {
+            slug: super::review::DUE_FOR_REVIEW.into(),
+            name: "Due for review".into(),
+            id: Default::default(),
+        }
+    }
+
+    /// Pseudo-topic listing the questions of a [`DbList`](super::list::DbList).
+    pub fn list(name: &str) -> Self {
+        Self {
+            slug: format!("{}{name}", super::list::LIST_PREFIX),
+            name: name.into(),
+            id: Default::default(),
+        }
+    }
+
+    /// Pseudo-topic listing the questions of a built-in [`CuratedList`].
+    pub fn curated(list: &CuratedList) -> Self {
+        Self {
+            slug: format!("{CURATED_PREFIX}{}", list.slug),
+            name: list.name.into(),
+            id: Default::default(),
+        }
+    }
+
+    /// The curated list, when the topic shows one.
+    pub fn curated_list(&self) -> Option<&'static CuratedList> {
+        self.slug
+            .strip_prefix(CURATED_PREFIX)
+            .and_then(CuratedList::by_slug)
+    }
+
+    /// Name of the list, when the topic shows a list.
+    pub fn list_name(&self) -> Option<&str> {
+        self.slug.strip_prefix(super::list::LIST_PREFIX)
+    }
+
+    /// Whether the topic is not a leetcode tag but a view like `all`.
+    pub fn is_pseudo(&self) -> bool {
+        self.slug == "all"
+            || self.slug == super::review::DUE_FOR_REVIEW
+            || self.list_name().is_some()
+            || self.slug.starts_with(CURATED_PREFIX)
     }
 
     pub fn fetch_all<'a>(db: &'a Database<'a>) -> DBResult<Vec<DbTopic>> {
         let r = db.r_transaction()?;
-        let x = r.scan().primary::<Self>()?.all().into_iter().collect();
+        let x = r.scan().primary::<Self>()?.all().collect();
         Ok(x)
     }
 
     pub fn fetch_questions<'a>(&self, db: &'a Database<'a>) -> DBResult<Vec<DbQuestion>> {
-        let q_ids = if self.slug.eq("all") {
-            (1..=DbQuestion::get_total_questions(db).unwrap())
-                .map(|x| x as u32)
-                .collect()
-        } else {
-            TopicQuestionMap::get_all_question_by_topic(self, db)?
-        };
-        let mut v = vec![];
-        for q_id in q_ids {
-            let q = DbQuestion::get_question_by_id(db, q_id)?;
-            v.push(q);
-        }
-        Ok(v)
+        QuestionQuery::new().topic(self.clone()).fetch(db)
+    }
+
+    /// Fetches the questions of several topics in a single read transaction,
+    /// intersecting (`And`) or unioning (`Or`) their question ids.
+    pub fn fetch_questions_for_topics<'a>(
+        topics: &[DbTopic],
+        op: TopicSetOp,
+        db: &'a Database<'a>,
+    ) -> DBResult<Vec<DbQuestion>> {
+        QuestionQuery::new().topics(topics.to_vec(), op).fetch(db)
     }
 
     pub fn get_topic_by_slug<'a>(slug: &str, db: &'a Database<'a>) -> DBResult<Self> {
         let r = db.r_transaction()?;
 
-        Ok(r.get()
+        r.get()
             .primary(slug.to_string())?
-            .ok_or(crate::errors::DbErr::TopicsNotFoundInDb(slug.to_string()))?)
-    }
-
-    pub(crate) fn save_to_db<'a>(&self, db: &'a Database<'a>) -> DBResult<()> {
-        let rw = db.rw_transaction()?;
-        rw.insert(self.clone())?;
-        rw.commit()?;
-        Ok(())
+            .ok_or(DbErr::TopicsNotFoundInDb(slug.to_string()))
     }
 }
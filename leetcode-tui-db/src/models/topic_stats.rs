@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use native_db::transaction::RwTransaction;
+
+use super::{question::DbQuestion, *};
+
+/// Precomputed question counts of a topic, kept in sync whenever a question
+/// is saved or its status changes. The `all` pseudo-topic is included.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Default)]
+#[native_model(id = 5, version = 1)]
+#[native_db]
+pub struct DbTopicStats {
+    #[primary_key]
+    pub slug: String,
+    pub total: u32,
+    pub solved: u32,
+    pub attempted: u32,
+}
+
+#[derive(Default)]
+struct StatsDelta {
+    total: i64,
+    solved: i64,
+    attempted: i64,
+}
+
+impl StatsDelta {
+    fn add(&mut self, question: &DbQuestion, sign: i64) {
+        self.total += sign;
+        match question.status.as_deref() {
+            Some("ac") => self.solved += sign,
+            Some("notac") => self.attempted += sign,
+            _ => (),
+        }
+    }
+}
+
+fn apply(value: u32, delta: i64) -> u32 {
+    (value as i64 + delta).max(0) as u32
+}
+
+impl DbTopicStats {
+    fn new(slug: &str) -> Self {
+        Self {
+            slug: slug.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Part of the topic's questions that are solved, between `0.0` and `1.0`.
+    pub fn completion(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.solved as f64 / self.total as f64
+    }
+
+    /// Returns the stats of every topic, rebuilding them from the questions
+    /// table when they are out of step with it (e.g. never computed, or the
+    /// questions table was recreated by a schema upgrade).
+    pub fn fetch_all<'a>(db: &'a Database<'a>) -> DBResult<Vec<Self>> {
+        let r = db.r_transaction()?;
+        let stats: Vec<Self> = r.scan().primary::<Self>()?.all().collect();
+        let counted = stats
+            .iter()
+            .find(|s| s.slug == "all")
+            .map_or(0, |s| s.total);
+        if counted as u64 != r.len().primary::<DbQuestion>()? {
+            drop(r);
+            return Self::rebuild(db);
+        }
+        Ok(stats)
+    }
+
+    /// Recomputes the stats of every topic from scratch.
+    pub fn rebuild<'a>(db: &'a Database<'a>) -> DBResult<Vec<Self>> {
+        let rw = db.rw_transaction()?;
+        rw.drain().primary::<Self>()?;
+        let questions: Vec<DbQuestion> = rw.scan().primary::<DbQuestion>()?.all().collect();
+        for question in questions.iter() {
+            Self::record_change(&rw, None, Some(question))?;
+        }
+        let stats = rw.scan().primary::<Self>()?.all().collect();
+        rw.commit()?;
+        Ok(stats)
+    }
+
+    /// Moves the counts of `old` (if any) over to `new` (if any) within the
+    /// given transaction, for each of their topics and for `all`.
+    pub(crate) fn record_change(
+        rw: &RwTransaction,
+        old: Option<&DbQuestion>,
+        new: Option<&DbQuestion>,
+    ) -> DBResult<()> {
+        let mut deltas: HashMap<&str, StatsDelta> = HashMap::new();
+        for (question, sign) in [(old, -1), (new, 1)] {
+            let Some(question) = question else {
+                continue;
+            };
+            for slug in question
+                .topics
+                .iter()
+                .map(|t| t.slug.as_str())
+                .chain(["all"])
+            {
+                deltas.entry(slug).or_default().add(question, sign);
+            }
+        }
+
+        for (slug, delta) in deltas {
+            if delta.total == 0 && delta.solved == 0 && delta.attempted == 0 {
+                continue;
+            }
+            let old_stats = rw.get().primary::<Self>(slug)?;
+            let mut stats = old_stats.clone().unwrap_or_else(|| Self::new(slug));
+            stats.total = apply(stats.total, delta.total);
+            stats.solved = apply(stats.solved, delta.solved);
+            stats.attempted = apply(stats.attempted, delta.attempted);
+            match old_stats {
+                Some(old_stats) => rw.update(old_stats, stats)?,
+                None => rw.insert(stats)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::{BTreeSet, HashMap};
+use std::ops::RangeInclusive;
+
+use native_db::transaction::RTransaction;
+use native_db::Database;
+
+use crate::errors::DBResult;
+use crate::models::list::DbList;
+use crate::models::note::DbNote;
+use crate::models::question::{DbQuestion, DbQuestionKey};
+use crate::models::review::{today, DbReview, DUE_FOR_REVIEW};
+use crate::models::topic::{DbTopic, TopicSetOp};
+use crate::models::TopicQuestionMap;
+
+type Predicate = Box<dyn Fn(&DbQuestion) -> bool + Send + Sync>;
+
+/// Builder for fetching questions in a single read transaction.
+///
+/// The most selective condition (topics, then the difficulty or status
+/// index, then the id range) picks the candidate questions, the remaining
+/// conditions are applied as filters. Results are ordered by id.
+///
+/// ```ignore
+/// let hard_dp = QuestionQuery::new()
+///     .topic(DbTopic::new("dynamic-programming"))
+///     .difficulty("Hard")
+///     .fetch(db)?;
+/// ```
+#[derive(Default)]
+pub struct QuestionQuery {
+    topics: Vec<DbTopic>,
+    topic_op: TopicSetOp,
+    ids: Option<RangeInclusive<u32>>,
+    difficulty: Option<String>,
+    status: Option<Option<String>>,
+    predicates: Vec<Predicate>,
+}
+
+impl QuestionQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only questions tagged with `topic`. The `all` pseudo-topic matches
+    /// every question, [`DUE_FOR_REVIEW`] the ones due for review today, and
+    /// list topics the questions of a user or [curated](crate::curated::CuratedList) list.
+    pub fn topic(mut self, topic: DbTopic) -> Self {
+        self.topics.push(topic);
+        self
+    }
+
+    /// Only questions tagged with all (`And`) or any (`Or`) of `topics`.
+    pub fn topics(mut self, topics: impl IntoIterator<Item = DbTopic>, op: TopicSetOp) -> Self {
+        self.topics.extend(topics);
+        self.topic_op = op;
+        self
+    }
+
+    pub fn ids(mut self, ids: RangeInclusive<u32>) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Only questions of the given difficulty: "Easy", "Medium" or "Hard".
+    pub fn difficulty(mut self, difficulty: &str) -> Self {
+        self.difficulty = Some(difficulty.into());
+        self
+    }
+
+    /// Only questions with the given status, `None` selects the ones never
+    /// attempted.
+    pub fn status(mut self, status: Option<&str>) -> Self {
+        self.status = Some(status.map(Into::into));
+        self
+    }
+
+    /// Only questions for which `predicate` returns true.
+    pub fn filter(
+        mut self,
+        predicate: impl Fn(&DbQuestion) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.predicates.push(Box::new(predicate));
+        self
+    }
+
+    pub fn fetch<'a>(&self, db: &'a Database<'a>) -> DBResult<Vec<DbQuestion>> {
+        let r = db.r_transaction()?;
+        self.fetch_in(&r)
+    }
+
+    pub(crate) fn fetch_in(&self, r: &RTransaction) -> DBResult<Vec<DbQuestion>> {
+        let mut questions = match self.topic_ids(r)? {
+            Some(ids) => {
+                let mut questions = Vec::with_capacity(ids.len());
+                for id in ids {
+                    match r.get().primary::<DbQuestion>(id)? {
+                        Some(question) => questions.push(question),
+                        // an orphaned topic mapping or list entry
+                        None => log::warn!("Skipping question {id} missing from the db"),
+                    }
+                }
+                questions
+            }
+            None => self.scan(r)?,
+        };
+        questions.retain(|q| self.matches(q));
+        questions.sort_by_key(|q| q.id);
+        if let [topic] = self.topics.as_slice() {
+            // a single list keeps its own order
+            if let Some(positions) = Self::list_positions(topic, r)? {
+                questions.sort_by_key(|q| positions.get(&q.id).copied());
+            }
+        }
+        let noted = DbNote::question_ids(r)?;
+        for question in questions.iter_mut() {
+            question.has_note = noted.contains(&question.id);
+        }
+        Ok(questions)
+    }
+
+    /// Position of each question in the user or curated list shown by the
+    /// topic, `None` for other topics.
+    fn list_positions(topic: &DbTopic, r: &RTransaction) -> DBResult<Option<HashMap<u32, usize>>> {
+        let ids = if let Some(name) = topic.list_name() {
+            DbList::get_in(name, r)?.question_ids
+        } else if let Some(curated) = topic.curated_list() {
+            curated.resolve_in(r)?.question_ids
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(
+            ids.into_iter().enumerate().map(|(i, id)| (id, i)).collect(),
+        ))
+    }
+
+    /// Question ids of the selected topics, `None` when the topics do not
+    /// restrict the result.
+    fn topic_ids(&self, r: &RTransaction) -> DBResult<Option<BTreeSet<u32>>> {
+        let (all, topics): (Vec<&DbTopic>, Vec<&DbTopic>) =
+            self.topics.iter().partition(|t| t.slug == "all");
+        if topics.is_empty() || (self.topic_op == TopicSetOp::Or && !all.is_empty()) {
+            return Ok(None);
+        }
+
+        let mut id_sets = topics
+            .iter()
+            .map(|t| {
+                if let Some(list) = t.list_name() {
+                    Ok(DbList::get_in(list, r)?.question_ids.into_iter().collect())
+                } else if let Some(curated) = t.curated_list() {
+                    Ok(curated.resolve_in(r)?.question_ids.into_iter().collect())
+                } else if t.slug == DUE_FOR_REVIEW {
+                    DbReview::due_question_ids(today(), r)
+                } else {
+                    TopicQuestionMap::get_question_ids_by_topic_slug(&t.slug, r)
+                }
+            })
+            .collect::<DBResult<Vec<_>>>()?;
+
+        Ok(Some(match self.topic_op {
+            TopicSetOp::Or => id_sets.into_iter().flatten().collect(),
+            TopicSetOp::And => {
+                // intersect starting from the smallest set to keep the work minimal
+                id_sets.sort_by_key(|s| s.len());
+                let mut sets = id_sets.into_iter();
+                let first = sets.next().unwrap_or_default();
+                sets.fold(first, |acc, s| acc.intersection(&s).copied().collect())
+            }
+        }))
+    }
+
+    fn scan(&self, r: &RTransaction) -> DBResult<Vec<DbQuestion>> {
+        let scan = r.scan();
+        Ok(if let Some(difficulty) = &self.difficulty {
+            scan.secondary::<DbQuestion>(DbQuestionKey::difficulty)?
+                .start_with(difficulty.clone())
+                .collect()
+        } else if let Some(Some(status)) = &self.status {
+            scan.secondary::<DbQuestion>(DbQuestionKey::status)?
+                .start_with(status.clone())
+                .collect()
+        } else if let Some(ids) = &self.ids {
+            scan.primary::<DbQuestion>()?.range(ids.clone()).collect()
+        } else {
+            scan.primary::<DbQuestion>()?.all().collect()
+        })
+    }
+
+    fn matches(&self, question: &DbQuestion) -> bool {
+        // secondary keys are matched by prefix, so compare exact values again
+        self.ids.iter().all(|ids| ids.contains(&question.id))
+            && self.difficulty.iter().all(|d| &question.difficulty == d)
+            && self.status.iter().all(|s| &question.status == s)
+            && self.predicates.iter().all(|p| p(question))
+    }
+}
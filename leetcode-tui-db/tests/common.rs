@@ -1,7 +1,7 @@
 use leetcode_tui_db::define_schema;
 use native_db::{Database, DatabaseBuilder};
 
-pub fn build_db<'a, 'b>(
+pub fn build_db<'a>(
     db_builder: &'a mut DatabaseBuilder,
 ) -> leetcode_tui_db::errors::DBResult<Database<'a>> {
     define_schema(db_builder)?;
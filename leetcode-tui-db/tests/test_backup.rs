@@ -0,0 +1,101 @@
+mod common;
+
+use common::build_db;
+use leetcode_core::types::run_submit_response::{ParsedResponse, TimeLimitExceeded};
+use leetcode_tui_db::errors::DbErr;
+use leetcode_tui_db::{
+    Backup, DbList, DbNote, DbQuestion, DbReview, DbSubmission, DbTopic, Grade, SubmissionKind,
+    BACKUP_VERSION,
+};
+use native_db::{Database, DatabaseBuilder};
+
+fn populate_db(db: &Database) {
+    let mut q = DbQuestion::new(
+        1,
+        "Two Sum",
+        "two-sum",
+        "Easy".into(),
+        false,
+        Some("ac".into()),
+    );
+    q.topics.push(DbTopic::new("array"));
+    q.topics.push(DbTopic::new("hash-table"));
+    q.ac_rate = 52.5;
+    q.has_solution = true;
+    q.save_to_db(db).unwrap();
+    let mut q = DbQuestion::new(
+        42,
+        "Trapping Rain Water",
+        "trapping-rain-water",
+        "Hard".into(),
+        true,
+        None,
+    );
+    q.topics.push(DbTopic::new("array"));
+    q.save_to_db(db).unwrap();
+
+    DbNote::save(1, "hash map, \"one pass\"\n\n- check before insert", db).unwrap();
+    DbList::add_question("prep", 42, db).unwrap();
+    DbList::add_question("prep", 1, db).unwrap();
+    let response = ParsedResponse::TimeLimitExceeded(TimeLimitExceeded { elapsed_time: 1200 });
+    DbSubmission::new(
+        42,
+        "rust",
+        SubmissionKind::Submit,
+        "fn main() {}",
+        &response,
+    )
+    .save_to_db(db)
+    .unwrap();
+    DbReview::record(1, Grade::Good, 20_000, db).unwrap();
+}
+
+#[test]
+fn test_backup_should_round_trip_through_json() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+    populate_db(&db);
+
+    let backup = Backup::export(&db).unwrap();
+    assert_eq!(backup.version, BACKUP_VERSION);
+    assert_eq!(backup.questions.len(), 2);
+    let restored = Backup::from_json(&backup.to_json().unwrap()).unwrap();
+    assert_eq!(restored, backup);
+
+    let mut db_builder = DatabaseBuilder::new();
+    let other = build_db(&mut db_builder).unwrap();
+    restored.import(&other).unwrap();
+    assert_eq!(Backup::export(&other).unwrap(), backup);
+    // topic mappings are rebuilt on import
+    let array = DbTopic::new("array").fetch_questions(&other).unwrap();
+    assert_eq!(array.len(), 2);
+}
+
+#[test]
+fn test_backup_should_round_trip_through_csv() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+    populate_db(&db);
+
+    let dir = std::env::temp_dir().join(format!("leetui-backup-{}", std::process::id()));
+    let backup = Backup::export(&db).unwrap();
+    backup.write_csv(&dir).unwrap();
+    let restored = Backup::read_csv(&dir);
+    std::fs::write(dir.join("version.txt"), "v2\n").unwrap();
+    let malformed = Backup::read_csv(&dir);
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(restored.unwrap(), backup);
+    assert!(matches!(malformed, Err(DbErr::InvalidBackupVersion(v)) if v == "v2"));
+}
+
+#[test]
+fn test_backup_should_reject_newer_versions() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+
+    let backup = Backup {
+        version: BACKUP_VERSION + 1,
+        ..Default::default()
+    };
+    assert!(backup.import(&db).is_err());
+}
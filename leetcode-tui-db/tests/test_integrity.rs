@@ -0,0 +1,64 @@
+mod common;
+
+use common::build_db;
+use leetcode_tui_db::{integrity, DbQuestion, DbTopic, DbTopicStats};
+use native_db::DatabaseBuilder;
+
+fn question(id: u32, slug: &str, topics: &[&str]) -> DbQuestion {
+    let mut q = DbQuestion::new(id, slug, slug, "Easy".into(), false, Some("ac".into()));
+    q.topics.extend(topics.iter().map(|t| DbTopic::new(t)));
+    q
+}
+
+#[test]
+fn test_check_should_pass_on_a_synced_db() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+    DbQuestion::save_many(
+        &[
+            question(1, "two-sum", &["array", "hash-table"]),
+            question(2, "add-two-numbers", &["linked-list"]),
+        ],
+        &db,
+    )
+    .unwrap();
+
+    let report = integrity::check(&db).unwrap();
+    assert!(report.is_ok());
+    assert!(report.duplicate_slugs.is_empty());
+    let rows = |name: &str| report.tables.iter().find(|t| t.name == name).unwrap().rows;
+    assert_eq!(rows("questions"), 2);
+    assert_eq!(rows("topic_question_map"), 3);
+    assert_eq!(rows("question_topic_map"), 3);
+}
+
+#[test]
+fn test_repair_should_drop_repeated_topics_and_report_shared_slugs() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+    DbQuestion::save_many(
+        &[
+            question(1, "two-sum", &["array", "array"]),
+            question(3001, "two-sum", &["array"]),
+        ],
+        &db,
+    )
+    .unwrap();
+
+    let report = integrity::check(&db).unwrap();
+    assert_eq!(report.duplicate_topics, vec![1]);
+    assert_eq!(report.duplicate_slugs["two-sum"], vec![1, 3001]);
+    assert!(!report.is_ok());
+
+    let report = integrity::repair(&db).unwrap();
+    assert!(report.is_ok());
+    assert_eq!(
+        DbQuestion::get_question_by_id(&db, 1).unwrap().topics.len(),
+        1
+    );
+    // shared slugs need a manual decision and are still reported
+    assert_eq!(report.duplicate_slugs.len(), 1);
+    let stats = DbTopicStats::fetch_all(&db).unwrap();
+    let array = stats.iter().find(|s| s.slug == "array").unwrap();
+    assert_eq!((array.total, array.solved), (2, 2));
+}
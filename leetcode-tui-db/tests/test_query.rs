@@ -0,0 +1,200 @@
+mod common;
+
+use common::build_db;
+use leetcode_tui_db::{
+    CuratedList, DbList, DbNote, DbQuestion, DbTopic, QuestionQuery, TopicSetOp, CURATED_LISTS,
+};
+use native_db::{Database, DatabaseBuilder};
+
+fn save(
+    db: &Database,
+    id: u32,
+    difficulty: &str,
+    status: Option<&str>,
+    topics: &[&str],
+) -> DbQuestion {
+    let mut q = DbQuestion::new(
+        id,
+        &format!("Question {id}"),
+        &format!("question-{id}"),
+        difficulty.into(),
+        false,
+        status.map(Into::into),
+    );
+    q.topics.extend(topics.iter().map(|t| DbTopic::new(t)));
+    q.save_to_db(db).unwrap();
+    q
+}
+
+// ids have gaps and go beyond the number of questions
+fn populate_db(db: &Database) {
+    save(db, 2, "Easy", Some("ac"), &["array"]);
+    save(db, 7, "Medium", None, &["array", "dynamic-programming"]);
+    save(db, 40, "Hard", Some("notac"), &["dynamic-programming"]);
+    save(db, 3001, "Hard", None, &["array"]);
+}
+
+fn ids(questions: Vec<DbQuestion>) -> Vec<u32> {
+    questions.into_iter().map(|q| q.id).collect()
+}
+
+#[test]
+fn test_should_fetch_all_questions_with_id_gaps() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+    populate_db(&db);
+
+    let qs = DbTopic::new("all").fetch_questions(&db).unwrap();
+    assert_eq!(ids(qs), vec![2, 7, 40, 3001]);
+}
+
+#[test]
+fn test_should_filter_by_indexed_fields_and_ranges() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+    populate_db(&db);
+
+    let qs = QuestionQuery::new().difficulty("Hard").fetch(&db).unwrap();
+    assert_eq!(ids(qs), vec![40, 3001]);
+
+    let qs = QuestionQuery::new().status(Some("ac")).fetch(&db).unwrap();
+    assert_eq!(ids(qs), vec![2]);
+
+    let qs = QuestionQuery::new().status(None).fetch(&db).unwrap();
+    assert_eq!(ids(qs), vec![7, 3001]);
+
+    let qs = QuestionQuery::new().ids(5..=100).fetch(&db).unwrap();
+    assert_eq!(ids(qs), vec![7, 40]);
+
+    let qs = QuestionQuery::new()
+        .difficulty("Hard")
+        .ids(1..=1000)
+        .fetch(&db)
+        .unwrap();
+    assert_eq!(ids(qs), vec![40]);
+}
+
+#[test]
+fn test_should_combine_topics_with_other_conditions() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+    populate_db(&db);
+
+    let qs = QuestionQuery::new()
+        .topic(DbTopic::new("array"))
+        .difficulty("Hard")
+        .fetch(&db)
+        .unwrap();
+    assert_eq!(ids(qs), vec![3001]);
+
+    let qs = QuestionQuery::new()
+        .topics(
+            [DbTopic::new("array"), DbTopic::new("dynamic-programming")],
+            TopicSetOp::Or,
+        )
+        .filter(|q| q.status.is_some())
+        .fetch(&db)
+        .unwrap();
+    assert_eq!(ids(qs), vec![2, 40]);
+}
+
+#[test]
+fn test_should_flag_questions_with_notes() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+    populate_db(&db);
+
+    DbNote::save(7, "use a monotonic stack", &db).unwrap();
+    assert_eq!(
+        DbNote::get(7, &db).unwrap().unwrap().content,
+        "use a monotonic stack"
+    );
+
+    let qs = QuestionQuery::new().fetch(&db).unwrap();
+    assert_eq!(
+        qs.iter()
+            .filter(|q| q.has_note)
+            .map(|q| q.id)
+            .collect::<Vec<_>>(),
+        vec![7]
+    );
+
+    // a blank note removes it
+    DbNote::save(7, "  \n", &db).unwrap();
+    assert!(DbNote::get(7, &db).unwrap().is_none());
+}
+
+#[test]
+fn test_should_fetch_list_questions_in_list_order() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+    populate_db(&db);
+
+    DbList::add_question("prep", 40, &db).unwrap();
+    DbList::add_question("prep", 2, &db).unwrap();
+    assert!(!DbList::add_question("prep", 2, &db).unwrap());
+    DbList::add_question("prep", 3001, &db).unwrap();
+    assert!(DbList::remove_question("prep", 3001, &db).unwrap());
+
+    let list = DbTopic::list("prep");
+    assert!(list.is_pseudo());
+    assert_eq!(ids(list.fetch_questions(&db).unwrap()), vec![40, 2]);
+
+    // a question missing from the db is skipped
+    DbList::add_question("prep", 9999, &db).unwrap();
+    assert_eq!(ids(list.fetch_questions(&db).unwrap()), vec![40, 2]);
+
+    let qs = QuestionQuery::new()
+        .topics([list, DbTopic::new("array")], TopicSetOp::And)
+        .fetch(&db)
+        .unwrap();
+    assert_eq!(ids(qs), vec![2]);
+}
+
+#[test]
+fn test_list_should_round_trip_text_and_json() {
+    let list = DbList::from_text("prep", "# prep\n40\n\n2\n40\n").unwrap();
+    assert_eq!(list.question_ids, vec![40, 2]);
+    assert_eq!(DbList::from_text("prep", &list.to_text()).unwrap(), list);
+    assert_eq!(DbList::from_json(&list.to_json().unwrap()).unwrap(), list);
+    assert!(DbList::from_text("prep", "two-sum").is_err());
+}
+
+#[test]
+fn test_should_resolve_curated_lists_by_title_slug() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+    for (id, slug) in [
+        (1, "two-sum"),
+        (217, "contains-duplicate"),
+        (121, "best-time-to-buy-and-sell-stock"),
+        // shares its prefix with "two-sum"
+        (653, "two-sum-iv-input-is-a-bst"),
+    ] {
+        DbQuestion::new(id, slug, slug, "Easy".into(), false, None)
+            .save_to_db(&db)
+            .unwrap();
+    }
+
+    let lens: Vec<usize> = CURATED_LISTS
+        .iter()
+        .map(|l| l.title_slugs().count())
+        .collect();
+    assert_eq!(lens, vec![75, 150, 169]);
+
+    let blind = CuratedList::by_slug("blind-75").unwrap();
+    let (_, resolved) = CuratedList::resolve_all(&db)
+        .unwrap()
+        .into_iter()
+        .find(|(l, _)| *l == blind)
+        .unwrap();
+    assert_eq!(resolved.question_ids, vec![1, 121, 217]);
+    assert_eq!(resolved.missing.len(), 72);
+    assert!(!resolved.missing.contains(&"two-sum"));
+
+    // questions keep the curated order
+    let topic = DbTopic::curated(blind);
+    assert!(topic.is_pseudo());
+    assert_eq!(topic.curated_list(), Some(blind));
+    assert_eq!(ids(topic.fetch_questions(&db).unwrap()), vec![1, 121, 217]);
+}
@@ -2,10 +2,10 @@ mod common;
 
 use common::build_db;
 use leetcode_core::types::problemset_question_list::Root;
-use leetcode_tui_db::models::question::DbQuestion;
+use leetcode_tui_db::models::{question::DbQuestion, topic::DbTopic};
 use native_db::{Database, DatabaseBuilder};
 
-static JSON: &'static str = r#"{
+static JSON: &str = r#"{
             "data": {
                 "problemsetQuestionList": {
                     "total": 2777,
@@ -128,3 +128,88 @@ fn test_it_should_add_a_new_question_to_db() {
     assert_eq!(x.id, 5);
     assert_eq!(x.title, "helloworld");
 }
+
+#[test]
+fn test_save_many_should_upsert_questions_and_mappings() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+
+    let root: Root = serde_json::from_str(JSON).unwrap();
+    let questions = root
+        .get_questions()
+        .into_iter()
+        .map(|q| DbQuestion::try_from(q).unwrap())
+        .collect::<Vec<_>>();
+    DbQuestion::save_many(&questions, &db).unwrap();
+    // saving the same page again must not fail on duplicates
+    DbQuestion::save_many(&questions, &db).unwrap();
+    assert_eq!(
+        DbQuestion::get_total_questions(&db).unwrap(),
+        questions.len()
+    );
+
+    // a question moved to another topic leaves its old topic
+    let mut moved = questions[0].clone();
+    let old_topic = moved.topics[0].clone();
+    moved.topics = vec![DbTopic::new("graph")];
+    DbQuestion::save_many(&[moved.clone()], &db).unwrap();
+
+    let ids = |topic: &DbTopic| {
+        topic
+            .fetch_questions(&db)
+            .unwrap()
+            .into_iter()
+            .map(|q| q.id)
+            .collect::<Vec<_>>()
+    };
+    assert!(!ids(&old_topic).contains(&moved.id));
+    assert_eq!(ids(&DbTopic::new("graph")), vec![moved.id]);
+}
+
+#[test]
+fn test_should_record_submissions_per_question() {
+    use leetcode_core::types::run_submit_response::{ParsedResponse, TimeLimitExceeded};
+    use leetcode_tui_db::{DbSubmission, SubmissionKind};
+
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+
+    let response = ParsedResponse::TimeLimitExceeded(TimeLimitExceeded { elapsed_time: 1200 });
+    DbSubmission::new(1, "rust", SubmissionKind::Submit, "fn main() {}", &response)
+        .save_to_db(&db)
+        .unwrap();
+    DbSubmission::new(11, "rust", SubmissionKind::Run, "fn main() {}", &response)
+        .save_to_db(&db)
+        .unwrap();
+
+    let submissions = DbSubmission::fetch_for_question(1, &db).unwrap();
+    assert_eq!(submissions.len(), 1);
+    assert_eq!(submissions[0].verdict, "Time Limit Exceeded");
+    assert_eq!(submissions[0].runtime.as_deref(), Some("1200 ms"));
+    assert_eq!(submissions[0].kind, SubmissionKind::Submit);
+    assert!(DbSubmission::fetch_for_question(2, &db).unwrap().is_empty());
+}
+
+#[test]
+fn test_should_keep_a_run_and_a_submit_of_the_same_code() {
+    use leetcode_core::types::run_submit_response::{ParsedResponse, TimeLimitExceeded};
+    use leetcode_tui_db::{DbSubmission, SubmissionKind};
+
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+
+    let response = ParsedResponse::TimeLimitExceeded(TimeLimitExceeded { elapsed_time: 1200 });
+    for kind in [SubmissionKind::Run, SubmissionKind::Submit] {
+        DbSubmission::new(1, "rust", kind, "fn main() {}", &response)
+            .save_to_db(&db)
+            .unwrap();
+    }
+
+    let mut kinds: Vec<_> = DbSubmission::fetch_for_question(1, &db)
+        .unwrap()
+        .into_iter()
+        .map(|s| s.kind.to_string())
+        .collect();
+    kinds.sort();
+    assert_eq!(kinds, ["Run", "Submit"]);
+}
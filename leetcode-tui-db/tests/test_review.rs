@@ -0,0 +1,57 @@
+mod common;
+
+use common::build_db;
+use leetcode_tui_db::{DbQuestion, DbReview, DbTopic, Grade, QuestionQuery, DUE_FOR_REVIEW};
+use native_db::DatabaseBuilder;
+
+#[test]
+fn test_review_should_follow_sm2_intervals() {
+    let mut review = DbReview::new(1, 100);
+
+    review.review(Grade::Good, 100);
+    assert_eq!((review.interval, review.due), (1, 101));
+    review.review(Grade::Good, 101);
+    assert_eq!((review.interval, review.due), (6, 107));
+    review.review(Grade::Easy, 107);
+    assert_eq!(review.repetitions, 3);
+    assert!(review.interval > 6);
+    let ease = review.ease;
+
+    // forgetting restarts the schedule and lowers the ease
+    review.review(Grade::Again, 130);
+    assert_eq!(
+        (review.interval, review.repetitions, review.due),
+        (1, 0, 131)
+    );
+    assert!(review.ease < ease);
+
+    for _ in 0..10 {
+        review.review(Grade::Again, 130);
+    }
+    assert_eq!(review.ease, 1.3);
+}
+
+#[test]
+fn test_should_list_questions_due_for_review() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+
+    for id in [1, 2, 3] {
+        DbQuestion::new(id, "q", "q", "Easy".into(), false, Some("ac".into()))
+            .save_to_db(&db)
+            .unwrap();
+    }
+    let today = leetcode_tui_db::models::review::today();
+    // due tomorrow
+    DbReview::record(1, Grade::Good, today, &db).unwrap();
+    // due yesterday
+    DbReview::record(2, Grade::Good, today - 2, &db).unwrap();
+
+    assert_eq!(DbReview::due_count(today, &db).unwrap(), 1);
+    let due = QuestionQuery::new()
+        .topic(DbTopic::new(DUE_FOR_REVIEW))
+        .fetch(&db)
+        .unwrap();
+    assert_eq!(due.iter().map(|q| q.id).collect::<Vec<_>>(), vec![2]);
+    assert!(DbTopic::due_for_review().is_pseudo());
+}
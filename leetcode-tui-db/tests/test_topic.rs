@@ -2,10 +2,14 @@ mod common;
 
 use common::build_db;
 use leetcode_core::types::problemset_question_list::Root;
-use leetcode_tui_db::models::{question::DbQuestion, topic::DbTopic};
+use leetcode_tui_db::models::{
+    question::DbQuestion,
+    topic::{DbTopic, TopicSetOp},
+    topic_stats::DbTopicStats,
+};
 use native_db::{Database, DatabaseBuilder};
 
-static JSON: &'static str = r#"{
+static JSON: &str = r#"{
             "data": {
                 "problemsetQuestionList": {
                     "total": 2777,
@@ -112,6 +116,15 @@ fn test_should_fetch_all_topics_from_the_db() {
     populate_db(&db);
     let topics = DbTopic::fetch_all(&db).unwrap();
     assert_eq!(topics.len(), 2);
+    assert_eq!(topics[0].name, "String");
+    assert_eq!(topics[0].id, "VG9waWNUYWdOb2RlOjEw");
+}
+
+#[test]
+fn test_should_name_topics_created_from_slug() {
+    let topic = DbTopic::new("dynamic-programming");
+    assert_eq!(topic.name, "Dynamic Programming");
+    assert_eq!(DbTopic::new("all").name, "All");
 }
 
 #[test]
@@ -132,3 +145,71 @@ fn test_should_fetch_all_questions_for_a_topic() {
 
     assert_eq!(qs.len(), 2);
 }
+
+#[test]
+fn test_should_combine_questions_of_multiple_topics() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+
+    populate_db(&db);
+    let mut q = DbQuestion::new(5, "Both", "both", "Easy".into(), false, None);
+    q.topics.push(DbTopic::new("string"));
+    q.topics.push(DbTopic::new("trees"));
+    q.save_to_db(&db).unwrap();
+    // shares "string" as a prefix and must not leak into "string" results
+    let mut q = DbQuestion::new(6, "Prefix", "prefix", "Easy".into(), false, None);
+    q.topics.push(DbTopic::new("string-matching"));
+    q.save_to_db(&db).unwrap();
+
+    let topics = [DbTopic::new("string"), DbTopic::new("trees")];
+
+    let qs = DbTopic::fetch_questions_for_topics(&topics, TopicSetOp::And, &db).unwrap();
+    assert_eq!(qs.iter().map(|q| q.id).collect::<Vec<_>>(), vec![5]);
+
+    let qs = DbTopic::fetch_questions_for_topics(&topics, TopicSetOp::Or, &db).unwrap();
+    assert_eq!(
+        qs.iter().map(|q| q.id).collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+
+    let topics = [DbTopic::new("all"), DbTopic::new("trees")];
+    let qs = DbTopic::fetch_questions_for_topics(&topics, TopicSetOp::And, &db).unwrap();
+    assert_eq!(qs.len(), 3);
+    let qs = DbTopic::fetch_questions_for_topics(&topics, TopicSetOp::Or, &db).unwrap();
+    assert_eq!(qs.len(), 6);
+}
+
+fn stats_of(slug: &str, db: &Database) -> DbTopicStats {
+    DbTopicStats::fetch_all(db)
+        .unwrap()
+        .into_iter()
+        .find(|s| s.slug == slug)
+        .unwrap()
+}
+
+#[test]
+fn test_should_keep_topic_stats_in_sync() {
+    let mut db_builder = DatabaseBuilder::new();
+    let db = build_db(&mut db_builder).unwrap();
+
+    populate_db(&db);
+    // saving an existing question again must not count it twice
+    populate_db(&db);
+
+    let string = stats_of("string", &db);
+    assert_eq!((string.total, string.solved, string.attempted), (2, 0, 1));
+    let all = stats_of("all", &db);
+    assert_eq!((all.total, all.solved, all.attempted), (4, 0, 1));
+
+    let mut q = DbQuestion::get_question_by_id(&db, 1).unwrap();
+    q.mark_accepted(&db).unwrap();
+    let mut q = DbQuestion::get_question_by_id(&db, 3).unwrap();
+    q.mark_attempted(&db).unwrap();
+
+    let string = stats_of("string", &db);
+    assert_eq!((string.total, string.solved, string.attempted), (2, 1, 0));
+    let trees = stats_of("trees", &db);
+    assert_eq!((trees.total, trees.solved, trees.attempted), (2, 0, 1));
+    assert_eq!(DbTopicStats::rebuild(&db).unwrap().len(), 3);
+    assert_eq!(stats_of("string", &db), string);
+}
@@ -1,9 +1,10 @@
-use leetcode_tui_core::{emit, Event, UBStrSender};
+use leetcode_tui_core::{emit, Event, SendError, UBStrSender};
 
 use color_eyre::Result;
-use leetcode_tui_config::{constants::EDITOR, key::Key};
-use leetcode_tui_db::{DbQuestion, DbTopic};
-use leetcode_tui_shared::tui::Term;
+use crossterm::event::MouseEvent;
+use leetcode_tui_config::{constants::EDITOR, key::Key, CONFIG};
+use leetcode_tui_db::{DbQuestion, DbTopic, TopicSetOp};
+use leetcode_tui_shared::{layout::Window, tui::Term};
 
 use crate::{ctx::Ctx, executor::Executor, root::Root, signals::Signals};
 
@@ -15,6 +16,7 @@ pub struct App {
 
 impl App {
     pub async fn run() -> Result<()> {
+        Window::set_options((&CONFIG.as_ref().layout).into());
         let term = Term::start()?;
         let signals = Signals::start()?;
         let mut app = Self {
@@ -30,19 +32,24 @@ impl App {
                     break;
                 }
                 Event::Input(sender, default_input) => app.dispatch_input(sender, default_input),
+                Event::Prompt(prompt, default_input, answer) => {
+                    app.dispatch_prompt(prompt, default_input, answer)
+                }
                 Event::Key(key) => app.dispatch_key(key),
+                Event::Mouse(mouse) => app.dispatch_mouse(mouse),
                 Event::Render(_) => app.dispatch_render(),
+                Event::Resize(cols, rows) => app.dispatch_resize(cols, rows),
                 Event::Topic(topic) => app.dispatch_topic_update(topic),
+                Event::Topics(topics, op) => app.dispatch_topics_update(topics, op),
                 Event::Questions(qs) => app.dispatch_question_update(qs),
-                Event::Popup(title, lines) => app.dispatch_popup(title, lines),
+                Event::Popup(title, lines, on_close) => app.dispatch_popup(title, lines, on_close),
                 Event::SelectPopup(maybe_title, lines, result_sender) => {
                     app.dispatch_select_popup(maybe_title, lines, result_sender)
                 }
-                Event::Error(e) => app.dispatch_popup(Some("Error".into()), vec![e]),
-                Event::Open(file_path) => app.dispatch_opener(file_path),
+                Event::Error(e) => app.dispatch_popup(Some("Error".into()), vec![e], None),
+                Event::Open(file_path, done) => app.dispatch_opener(file_path, done),
                 e => app.dispatch_module_event(e),
                 // Event::Paste(str) => app.dispatch_paste(str),
-                // Event::Resize(..) => app.dispatch_resize(),
                 // Event::Stop(state, tx) => app.dispatch_stop(state, tx),
                 // Event::Call(exec, layer) => app.dispatch_call(exec, layer),
                 // event => app.dispatch_module(event),
@@ -53,10 +60,29 @@ impl App {
 
     fn dispatch_key(&mut self, key: impl Into<Key>) {
         if Executor::handle(&mut self.cx, key.into()) {
+            self.follow_hovered();
+            emit!(Render);
+        }
+    }
+
+    fn dispatch_mouse(&mut self, mouse: MouseEvent) {
+        if Executor::mouse(&mut self.cx, mouse) {
+            self.follow_hovered();
             emit!(Render);
         }
     }
 
+    /// Points the preview at the hovered question, called wherever the
+    /// hovered question or the layout may change.
+    fn follow_hovered(&mut self) {
+        if Window::default().options.show_preview {
+            let hovered = self.cx.content.get_questions().hovered();
+            self.cx
+                .preview
+                .follow(hovered.map(|q| q.title_slug.as_str()));
+        }
+    }
+
     fn dispatch_topic_update(&mut self, topic: DbTopic) {
         self.cx
             .content
@@ -64,8 +90,16 @@ impl App {
             .get_questions_by_topic(topic)
     }
 
+    fn dispatch_topics_update(&mut self, topics: Vec<DbTopic>, op: TopicSetOp) {
+        self.cx
+            .content
+            .get_questions_mut()
+            .get_questions_by_topics(topics, op)
+    }
+
     fn dispatch_question_update(&mut self, questions: Vec<DbQuestion>) {
         self.cx.content.get_questions_mut().set_questions(questions);
+        self.follow_hovered();
         emit!(Render);
     }
 
@@ -77,8 +111,19 @@ impl App {
         }
     }
 
-    fn dispatch_popup(&mut self, title: Option<String>, lines: Vec<String>) {
-        self.cx.popup.reset(title, lines);
+    fn dispatch_resize(&mut self, cols: u16, rows: u16) {
+        Window::resize(cols, rows);
+        self.cx.content.resize();
+        emit!(Render);
+    }
+
+    fn dispatch_popup(
+        &mut self,
+        title: Option<String>,
+        lines: Vec<String>,
+        on_close: Option<tokio::sync::oneshot::Sender<()>>,
+    ) {
+        self.cx.popup.reset(title, lines, on_close);
         self.cx.popup.toggle();
         emit!(Render);
     }
@@ -94,7 +139,11 @@ impl App {
         emit!(Render);
     }
 
-    fn dispatch_opener(&mut self, file_path: std::path::PathBuf) {
+    fn dispatch_opener(
+        &mut self,
+        file_path: std::path::PathBuf,
+        done: Option<tokio::sync::oneshot::Sender<()>>,
+    ) {
         // TODO: unwraps handling
         self.signals.stop_looking_for_io_events();
         if let Some(term) = &mut self.term {
@@ -102,7 +151,7 @@ impl App {
             let editor = EDITOR.get().expect("editor not set");
             std::process::Command::new("sh")
                 .arg("-c")
-                .arg(&format!(
+                .arg(format!(
                     r#"{} "{}""#,
                     editor,
                     file_path.as_os_str().to_str().unwrap()
@@ -115,14 +164,51 @@ impl App {
             emit!(Render);
         }
         self.signals.start_looking_for_io_events();
+        if let Some(done) = done {
+            done.send(()).ok();
+        }
     }
 
     fn dispatch_module_event(&mut self, e: Event) {
         match e {
-            Event::QuestionFilter(needle) => self.cx.content.get_questions_mut().filter_by(needle),
-            Event::QuestionUpdate => self.cx.content.get_topic().notify_change(),
+            Event::QuestionFilter(needle) => {
+                let result = self.cx.content.get_questions_mut().filter_by(needle);
+                self.cx.input.set_error(result.err().map(|e| e.to_string()));
+            }
+            Event::PreviewFetch(slug) => self.cx.preview.fetch(slug),
+            Event::PreviewLoaded(slug, result) => self.cx.preview.loaded(slug, result),
+            Event::Article(title, markdown, source) => {
+                self.cx.article.open(title, &markdown, source)
+            }
+            Event::Goto(id_or_slug) => {
+                self.cx
+                    .content
+                    .jump_to_question(&id_or_slug)
+                    .emit_if_error()
+                    .ok();
+            }
+            Event::QuestionUpdate => {
+                self.cx.command.invalidate();
+                let topic = self.cx.content.get_topic_mut();
+                topic.refresh();
+                topic.notify_change();
+            }
             _ => (),
         }
+        self.follow_hovered();
+        emit!(Render);
+    }
+
+    fn dispatch_prompt(
+        &mut self,
+        prompt: String,
+        default_input: Option<String>,
+        answer: tokio::sync::oneshot::Sender<Option<String>>,
+    ) {
+        if !self.cx.input.visible {
+            self.cx.input.toggle();
+        }
+        self.cx.input.reset_for_prompt(prompt, default_input, answer);
         emit!(Render);
     }
 
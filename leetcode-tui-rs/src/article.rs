@@ -0,0 +1,79 @@
+use leetcode_tui_config::CONFIG;
+use leetcode_tui_core::article::ArticleLine;
+use leetcode_tui_core::utils::wrap;
+use leetcode_tui_shared::layout::GetWindowStats;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, ScrollbarState, Widget};
+
+use crate::ctx::Ctx;
+use crate::utils::render_scrollbar;
+
+/// Reader of a solution article, drawn over the panes like a popup.
+pub struct Article<'a> {
+    cx: &'a Ctx,
+}
+
+impl<'a> Article<'a> {
+    pub(super) fn new(cx: &'a Ctx) -> Self {
+        Self { cx }
+    }
+
+    /// Rows of the article wrapped to `width`, the rows
+    /// [`scroll_by`](leetcode_tui_core::article::Article::scroll_by) counts.
+    fn rows(&self, width: u16) -> Vec<Line<'_>> {
+        let c_def = &CONFIG.as_ref().theme.defaults;
+        let heading = Style::default()
+            .fg(c_def.info.into())
+            .add_modifier(Modifier::BOLD);
+        let code = Style::default()
+            .fg(c_def.fg.into())
+            .bg(c_def.bg_highlight.into());
+        let note = Style::default()
+            .fg(c_def.comment.into())
+            .add_modifier(Modifier::ITALIC);
+        let mut rows = vec![];
+        for line in self.cx.article.lines() {
+            let style = match line {
+                ArticleLine::Heading(_) => heading,
+                ArticleLine::Text(_) => Style::default(),
+                ArticleLine::Code(_) => code,
+                ArticleLine::Note(_) => note,
+            };
+            rows.extend(
+                wrap(line.text(), width)
+                    .into_iter()
+                    .map(|row| Line::styled(row, style)),
+            );
+        }
+        rows
+    }
+}
+
+impl<'a> Widget for Article<'a> {
+    fn render(self, _area: Rect, buf: &mut Buffer) {
+        let c_def = &CONFIG.as_ref().theme.defaults;
+        let areas = self.get_window().root.popup;
+        Clear.render(areas.outer, buf);
+        Block::default()
+            .title(self.cx.article.title())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(c_def.info.into()))
+            .render(areas.outer, buf);
+        let scroll = self.cx.article.scroll();
+        let rows = self.rows(areas.inner.width);
+        let height = rows.len();
+        Paragraph::new(rows)
+            .scroll((scroll.min(u16::MAX as usize) as u16, 0))
+            .style(Style::default().fg(c_def.fg.into()))
+            .render(areas.inner, buf);
+        if height > areas.inner.height as usize {
+            let clamp = |n: usize| n.min(u16::MAX as usize) as u16;
+            // positions run up to the scroll showing the last row
+            let state = ScrollbarState::default()
+                .content_length(clamp(height - areas.inner.height as usize))
+                .viewport_content_length(areas.inner.height)
+                .position(clamp(scroll));
+            render_scrollbar(state, areas.outer, buf);
+        }
+    }
+}
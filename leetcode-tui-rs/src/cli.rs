@@ -0,0 +1,247 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use color_eyre::{eyre::eyre, Result};
+use leetcode_tui_config::clients::Db;
+use leetcode_tui_config::CONFIG;
+use leetcode_tui_db::integrity::{self, IntegrityReport};
+use leetcode_tui_db::{Backup, DbList};
+
+/// Leetcode terminal UI. Runs the TUI when no command is given.
+#[derive(Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Back up questions, topics, notes, lists, submissions and reviews
+    Export {
+        /// Output file for json, output directory for csv. Json is written
+        /// to stdout when omitted
+        path: Option<PathBuf>,
+        #[arg(short, long, value_enum, default_value_t = BackupFormat::Json)]
+        format: BackupFormat,
+    },
+    /// Restore a backup made with `export`, replacing rows with the same key
+    Import {
+        path: PathBuf,
+        /// Defaults to csv for directories and json otherwise
+        #[arg(short, long, value_enum)]
+        format: Option<BackupFormat>,
+    },
+    /// Check the database for broken topic mappings, or repair them
+    Db {
+        #[command(subcommand)]
+        command: DbCommand,
+    },
+    /// Manage question lists
+    List {
+        #[command(subcommand)]
+        command: ListCommand,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DbCommand {
+    /// Report the file size, tables, orphan and duplicate mappings
+    Check,
+    /// Rebuild the topic mappings and stats from the questions
+    Repair,
+}
+
+#[derive(Subcommand)]
+pub enum ListCommand {
+    /// Print the names of all lists with their number of questions
+    Ls,
+    /// Write a list to stdout or to a file
+    Export {
+        name: String,
+        #[arg(short, long, value_enum, default_value_t = ListFormat::Text)]
+        format: ListFormat,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Read a list from a file, replacing a list with the same name
+    Import {
+        file: PathBuf,
+        /// Name of the list, defaults to the file name for text files
+        #[arg(short, long)]
+        name: Option<String>,
+        /// Defaults to json for `.json` files and text otherwise
+        #[arg(short, long, value_enum)]
+        format: Option<ListFormat>,
+    },
+    /// Delete a list
+    Delete { name: String },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ListFormat {
+    /// One question id per line, `#` starts a comment
+    Text,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum BackupFormat {
+    /// A single versioned json document
+    Json,
+    /// A directory with one csv file per table and a `version.txt`
+    Csv,
+}
+
+impl ListFormat {
+    fn of(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => ListFormat::Json,
+            _ => ListFormat::Text,
+        }
+    }
+}
+
+impl Command {
+    pub fn run(self, db: &Db) -> Result<()> {
+        match self {
+            Command::Export { path, format } => {
+                let backup = Backup::export(db)?;
+                match (format, path) {
+                    (BackupFormat::Json, Some(path)) => std::fs::write(path, backup.to_json()?)?,
+                    (BackupFormat::Json, None) => println!("{}", backup.to_json()?),
+                    (BackupFormat::Csv, Some(dir)) => backup.write_csv(&dir)?,
+                    (BackupFormat::Csv, None) => {
+                        return Err(eyre!("Pass the output directory for csv exports"))
+                    }
+                }
+            }
+            Command::Import { path, format } => {
+                let format = format.unwrap_or(if path.is_dir() {
+                    BackupFormat::Csv
+                } else {
+                    BackupFormat::Json
+                });
+                let backup = match format {
+                    BackupFormat::Json => Backup::from_json(&std::fs::read_to_string(&path)?)?,
+                    BackupFormat::Csv => Backup::read_csv(&path)?,
+                };
+                backup.import(db)?;
+                println!(
+                    "Imported {} questions, {} notes, {} lists, {} submissions and {} reviews",
+                    backup.questions.len(),
+                    backup.notes.len(),
+                    backup.lists.len(),
+                    backup.submissions.len(),
+                    backup.reviews.len()
+                );
+            }
+            Command::Db { command } => command.run(db)?,
+            Command::List { command } => command.run(db)?,
+        }
+        Ok(())
+    }
+}
+
+impl DbCommand {
+    fn run(self, db: &Db) -> Result<()> {
+        let path = CONFIG.as_ref().db_path();
+        let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or_default();
+        println!("{path}: {:.1} MiB", size as f64 / (1024.0 * 1024.0));
+        let report = match self {
+            DbCommand::Check => integrity::check(db)?,
+            DbCommand::Repair => {
+                let before = integrity::check(db)?;
+                print_findings(&before);
+                println!("Repairing");
+                integrity::repair(db)?
+            }
+        };
+        for table in report.tables.iter() {
+            println!(
+                "  {:<20} model {} v{} {:>8} rows",
+                table.name, table.model_id, table.model_version, table.rows
+            );
+        }
+        print_findings(&report);
+        if report.is_ok() {
+            println!("No problems found");
+        } else {
+            println!("Run `leetui db repair` to rebuild the topic mappings");
+        }
+        Ok(())
+    }
+}
+
+fn print_findings(report: &IntegrityReport) {
+    let count = |name: &str, n: usize| {
+        if n > 0 {
+            println!("{n} {name}");
+        }
+    };
+    count("mappings to missing questions", report.orphan_maps.len());
+    count(
+        "mappings to topics the question lost",
+        report.stale_maps.len(),
+    );
+    count("question topics without mappings", report.missing_maps);
+    count(
+        "questions listing a topic twice",
+        report.duplicate_topics.len(),
+    );
+    for (slug, ids) in report.duplicate_slugs.iter() {
+        println!("{slug} is shared by questions {ids:?}");
+    }
+}
+
+impl ListCommand {
+    fn run(self, db: &Db) -> Result<()> {
+        match self {
+            ListCommand::Ls => {
+                for list in DbList::fetch_all(db)? {
+                    println!("{} ({})", list.name, list.question_ids.len());
+                }
+            }
+            ListCommand::Export {
+                name,
+                format,
+                output,
+            } => {
+                let list = DbList::get(&name, db)?;
+                let content = match format {
+                    ListFormat::Text => list.to_text(),
+                    ListFormat::Json => list.to_json()?,
+                };
+                match output {
+                    Some(path) => std::fs::write(path, content)?,
+                    None => print!("{content}"),
+                }
+            }
+            ListCommand::Import { file, name, format } => {
+                let content = std::fs::read_to_string(&file)?;
+                let mut list = match format.unwrap_or_else(|| ListFormat::of(&file)) {
+                    ListFormat::Json => DbList::from_json(&content)?,
+                    ListFormat::Text => {
+                        let stem = file.file_stem().and_then(|s| s.to_str());
+                        let name = name
+                            .clone()
+                            .or(stem.map(String::from))
+                            .ok_or_else(|| eyre!("Pass the list name with --name"))?;
+                        DbList::from_text(&name, &content)?
+                    }
+                };
+                if let Some(name) = name {
+                    list.name = name;
+                }
+                list.save_to_db(db)?;
+                println!(
+                    "Imported {} with {} questions",
+                    list.name,
+                    list.question_ids.len()
+                );
+            }
+            ListCommand::Delete { name } => DbList::delete(&name, db)?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use color_eyre::Result;
+use leetcode_tui_config::DB_CLIENT;
+use leetcode_tui_core::command::{Command, ExportFormat};
+use leetcode_tui_core::{emit, SendError};
+use leetcode_tui_db::Backup;
+
+use crate::{ctx::Ctx, utils::sync_questions};
+
+/// Runs a line typed in the `:` command line and adds it to the history.
+pub(super) fn run(cx: &mut Ctx, line: &str) -> bool {
+    let line = line.trim();
+    if line.is_empty() {
+        return true;
+    }
+    cx.command.push(line).emit_if_error().ok();
+    let Ok(command) = line.parse::<Command>().emit_if_error() else {
+        return true;
+    };
+    match command {
+        Command::Open(question) => {
+            cx.content
+                .get_questions()
+                .open(&question)
+                .emit_if_error()
+                .ok();
+        }
+        Command::Goto(question) => {
+            cx.content.jump_to_question(&question).emit_if_error().ok();
+        }
+        Command::Lang(language) => cx.content.get_questions_mut().set_language(language),
+        Command::Sort(sort) => {
+            cx.content.get_questions_mut().set_sort(sort);
+        }
+        Command::Topic(slug) => {
+            cx.content
+                .get_topic_mut()
+                .select(&slug)
+                .emit_if_error()
+                .ok();
+        }
+        Command::Sync => sync(),
+        Command::Export(path, format) => match export(&path, format) {
+            Ok(count) => emit!(Popup(vec![format!(
+                "Exported {count} questions to {}",
+                path.display()
+            )])),
+            Err(e) => emit!(Error(e.to_string())),
+        },
+        Command::Quit => emit!(Quit),
+    }
+    true
+}
+
+/// Downloads the question list in the background and reloads the topics.
+fn sync() {
+    tokio::spawn(async move {
+        match sync_questions(|_| Ok(())).await {
+            Ok(total) => {
+                emit!(QuestionUpdate);
+                emit!(Popup(vec![format!("Synced {total} questions")]));
+            }
+            Err(e) => emit!(Error(e.to_string())),
+        }
+    });
+}
+
+fn export(path: &Path, format: ExportFormat) -> Result<usize> {
+    let backup = Backup::export(DB_CLIENT.as_ref())?;
+    match format {
+        ExportFormat::Json => std::fs::write(path, backup.to_json()?)?,
+        ExportFormat::Csv => backup.write_csv(path)?,
+    }
+    Ok(backup.questions.len())
+}
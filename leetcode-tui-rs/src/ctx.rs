@@ -1,7 +1,14 @@
+use std::time::Instant;
+
+use leetcode_tui_config::key::Key;
+use leetcode_tui_core::article::Article;
+use leetcode_tui_config::utils::get_data_dir;
+use leetcode_tui_core::command::CommandLine;
 use leetcode_tui_core::content::MainContent;
 use leetcode_tui_core::help::Help;
 use leetcode_tui_core::input::Input;
 use leetcode_tui_core::popup::{Popup, SelectPopup};
+use leetcode_tui_core::preview::Preview;
 
 pub struct Ctx {
     pub content: MainContent,
@@ -9,6 +16,15 @@ pub struct Ctx {
     pub select_popup: SelectPopup<String>,
     pub input: Input,
     pub help: Help,
+    pub article: Article,
+    pub preview: Preview,
+    pub command: CommandLine,
+    /// Count typed before a motion, e.g. the `5` of `5j`.
+    pub count: Option<usize>,
+    /// Keys typed so far of a key sequence, e.g. the first `g` of `g g`.
+    pub pending: Vec<Key>,
+    /// Time and cell of the last left click, to tell double-clicks.
+    pub last_click: Option<(Instant, u16, u16)>,
 }
 
 impl Ctx {
@@ -19,6 +35,12 @@ impl Ctx {
             select_popup: Default::default(),
             input: Default::default(),
             help: Default::default(),
+            article: Default::default(),
+            preview: Default::default(),
+            command: CommandLine::load(&get_data_dir().join("command_history")),
+            count: None,
+            pending: vec![],
+            last_click: None,
         }
     }
 }
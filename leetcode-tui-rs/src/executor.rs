@@ -1,100 +1,433 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use leetcode_tui_config::keymap::{Action, Context, Lookup};
+use leetcode_tui_config::CONFIG;
 use leetcode_tui_config::{key::Key, utils::get_config_file_path};
-use leetcode_tui_core::emit;
+use leetcode_tui_core::{emit, SendError};
+use leetcode_tui_shared::layout::{LayoutOptions, Window};
 
 use crate::ctx::Ctx;
 
+/// Rows of a list scrolled by one step of the mouse wheel.
+const WHEEL_ROWS: isize = 3;
+/// Longest delay between the two clicks of a double-click.
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+/// Percent of the width the Topics pane grows or shrinks by.
+const TOPICS_STEP: i16 = 5;
+
 pub struct Executor;
 
 impl Executor {
-    pub fn handle(cx: &mut Ctx, key: Key) -> bool {
-        if matches!(key, Key::Char('?')) {
-            return cx.help.toggle();
+    /// Context receiving the keys, the topmost visible part of the UI.
+    fn context(cx: &Ctx) -> Option<Context> {
+        if cx.help.is_visible() {
+            Some(Context::Help)
+        } else if cx.popup.visible {
+            Some(Context::Popup)
+        } else if cx.select_popup.visible {
+            Some(Context::Select)
+        } else if cx.input.visible {
+            Some(Context::Input)
+        } else if cx.article.visible {
+            Some(Context::Article)
+        } else if cx.content.get_questions().is_stats_visible() {
+            Some(Context::Stats)
+        } else if cx.content.is_visible() {
+            Some(Context::List)
+        } else {
+            None
         }
+    }
 
-        // open config file
-        if matches!(key, Key::Char('c')) && !cx.input.visible {
-            emit!(Open(get_config_file_path()));
+    pub fn handle(cx: &mut Ctx, key: Key) -> bool {
+        let Some(context) = Self::context(cx) else {
             return false;
-        }
-
-        if cx.help.is_visible() {
-            return match key {
-                Key::Down | Key::Char('j') => cx.help.next(),
-                Key::Up | Key::Char('k') => cx.help.previous(),
-                Key::Char('?') | Key::Esc => cx.help.toggle(),
-                Key::Enter => {
-                    cx.help.toggle()
-                    //TODO: emit key event
-                    // emit!(Key());
-                }
+        };
+        let keymap = &CONFIG.as_ref().keymap;
+        if context == Context::Help && cx.help.is_filtering() {
+            // the filter is typed like a prompt
+            return match (keymap.input.action(key), key) {
+                (Some(Action::Cancel), _) => cx.help.clear_filter(),
+                (Some(Action::Confirm), _) => cx.help.stop_filtering(),
+                (Some(Action::DeleteChar), _) => cx.help.pop_filter_char(),
+                (Some(Action::Up), _) => cx.help.prev_item(),
+                (Some(Action::Down), _) => cx.help.next_item(),
+                (_, Key::Char(c)) => cx.help.push_filter_char(c),
                 _ => false,
             };
         }
+        cx.pending.push(key);
+        let action = match keymap.bindings(context).lookup(&cx.pending) {
+            Lookup::Prefix => return true,
+            Lookup::Action(action) => {
+                cx.pending.clear();
+                action
+            }
+            // a broken sequence is dropped, its last key handled on its own
+            Lookup::Unbound if cx.pending.len() > 1 => {
+                cx.pending.clear();
+                return Self::handle(cx, key);
+            }
+            Lookup::Unbound => {
+                cx.pending.clear();
+                return Self::unbound(cx, context, key);
+            }
+        };
+        let count = cx.count.take();
+        match (context, action, count) {
+            (Context::List, Action::JumpToQuestion, Some(id)) => cx
+                .content
+                .jump_to_question(&id.to_string())
+                .emit_if_error()
+                .unwrap_or_default(),
+            (
+                Context::List | Context::Stats,
+                Action::Up
+                | Action::Down
+                | Action::NextTopic
+                | Action::PrevTopic
+                | Action::PageUp
+                | Action::PageDown
+                | Action::HalfPageUp
+                | Action::HalfPageDown,
+                Some(count),
+            ) => {
+                let mut moved = false;
+                // stops at either end of the list
+                for _ in 0..count {
+                    if !Self::run(cx, context, action) {
+                        break;
+                    }
+                    moved = true;
+                }
+                moved
+            }
+            _ => Self::run(cx, context, action),
+        }
+    }
 
-        if cx.popup.visible {
-            return match key {
-                Key::Enter | Key::Esc => cx.popup.toggle(),
-                Key::Up | Key::Char('k') => cx.popup.scroll_up(),
-                Key::Down | Key::Char('j') => cx.popup.scroll_down(),
+    /// Clicks and the wheel, hit-tested against the layout of the window.
+    pub fn mouse(cx: &mut Ctx, mouse: MouseEvent) -> bool {
+        let Some(context) = Self::context(cx) else {
+            return false;
+        };
+        cx.pending.clear();
+        cx.count = None;
+        let (column, row) = (mouse.column, mouse.row);
+        let wheel = match mouse.kind {
+            MouseEventKind::ScrollUp => -1,
+            MouseEventKind::ScrollDown => 1,
+            MouseEventKind::Down(MouseButton::Left) => 0,
+            _ => return false,
+        };
+        let root = Window::default().root;
+        let center = &root.center_layout;
+        let (topic, question) = (&center.topic, &center.question);
+        match context {
+            Context::Popup => match wheel {
+                -1 => (0..WHEEL_ROWS).fold(false, |moved, _| cx.popup.scroll_up() || moved),
+                1 => (0..WHEEL_ROWS).fold(false, |moved, _| cx.popup.scroll_down() || moved),
                 _ => false,
-            };
+            },
+            Context::Select => match wheel {
+                -1 => cx.select_popup.prev_item(),
+                1 => cx.select_popup.next_item(),
+                _ => match root.popup.inner_row(column, row) {
+                    Some(row) => cx.select_popup.select_row(row) && cx.select_popup.close(),
+                    None => false,
+                },
+            },
+            Context::Article => cx.article.scroll_by(wheel * WHEEL_ROWS),
+            Context::Help => match wheel {
+                -1 => cx.help.prev_item(),
+                1 => cx.help.next_item(),
+                _ => false,
+            },
+            Context::Stats | Context::List if topic.contains(column, row) => {
+                if wheel != 0 {
+                    cx.content.get_topic_mut().scroll(wheel * WHEEL_ROWS)
+                } else if let Some(row) = topic.inner_row(column, row) {
+                    cx.content.get_topic_mut().hover_row(row)
+                } else {
+                    false
+                }
+            }
+            Context::List if center.preview.contains(column, row) => match wheel {
+                -1 => (0..WHEEL_ROWS).fold(false, |moved, _| cx.preview.scroll_up() || moved),
+                1 => (0..WHEEL_ROWS).fold(false, |moved, _| cx.preview.scroll_down() || moved),
+                _ => false,
+            },
+            Context::List if question.contains(column, row) => {
+                if wheel != 0 {
+                    return cx.content.get_questions_mut().scroll(wheel * WHEEL_ROWS);
+                }
+                let Some(row) = question.inner_row(column, row) else {
+                    return false;
+                };
+                let questions = cx.content.get_questions_mut();
+                if questions.hover_row(row) {
+                    // the first click of a double-click may hover the question
+                    cx.last_click = Some((Instant::now(), mouse.column, mouse.row));
+                    true
+                } else if Self::is_double_click(cx, mouse.column, mouse.row) {
+                    cx.content.get_questions().show_question_content()
+                } else {
+                    false
+                }
+            }
+            _ => false,
         }
+    }
 
-        if cx.select_popup.visible {
-            return match key {
-                Key::Enter => cx.select_popup.close(),
-                Key::Esc => cx.select_popup.close_unselected(),
-                Key::Up | Key::Char('k') => cx.select_popup.prev_item(),
-                Key::Down | Key::Char('j') => cx.select_popup.next_item(),
-                _ => false,
-            };
+    /// Whether a left click on the cell completes a double-click. A third
+    /// click starts over.
+    fn is_double_click(cx: &mut Ctx, column: u16, row: u16) -> bool {
+        let now = Instant::now();
+        let double = matches!(
+            cx.last_click,
+            Some((at, c, r)) if (c, r) == (column, row) && now.duration_since(at) <= DOUBLE_CLICK
+        );
+        cx.last_click = (!double).then_some((now, column, row));
+        double
+    }
+
+    /// Keys without an action: typed text and counts.
+    fn unbound(cx: &mut Ctx, context: Context, key: Key) -> bool {
+        match (context, key) {
+            (Context::Input, Key::Char(c)) => cx.input.char(c),
+            // unbound digits make up the count of the next action
+            (Context::List, Key::Char(c @ '0'..='9')) if c != '0' || cx.count.is_some() => {
+                let digit = c.to_digit(10).unwrap_or_default() as usize;
+                cx.count = Some(
+                    cx.count
+                        .unwrap_or_default()
+                        .saturating_mul(10)
+                        .saturating_add(digit),
+                );
+                true
+            }
+            _ => {
+                cx.count = None;
+                false
+            }
         }
+    }
+
+    fn run(cx: &mut Ctx, context: Context, action: Action) -> bool {
+        match (context, action) {
+            (_, Action::ToggleHelp) => cx.help.toggle(),
+            (_, Action::OpenConfig) => {
+                emit!(Open(get_config_file_path()));
+                false
+            }
+            (_, Action::Quit) => {
+                emit!(Quit);
+                false
+            }
 
-        if cx.input.visible {
-            return match key {
-                Key::Esc => cx.input.close(),
-                Key::Char(c) => cx.input.char(c),
-                Key::Backspace => cx.input.remove_char(),
-                Key::Up | Key::Down => {
-                    cx.input.close();
-                    leetcode_tui_core::emit!(Key(key.into()));
+            (Context::Help, Action::Down) => cx.help.next_item(),
+            (Context::Help, Action::Up) => cx.help.prev_item(),
+            (Context::Help, Action::Search) => cx.help.start_filtering(),
+            (Context::Help, Action::Cancel) => cx.help.clear_filter() || cx.help.toggle(),
+            (Context::Help, Action::Confirm) => {
+                let selected = cx.help.selected();
+                cx.help.toggle();
+                // only run actions of the part of the UI the help covers
+                if let Some((context, action)) =
+                    selected.filter(|(context, _)| Self::context(cx) == Some(*context))
+                {
+                    Self::run(cx, context, action);
+                }
+                true
+            }
+
+            (Context::Popup | Context::Select | Context::Article, Action::ToggleMaximize) => {
+                Self::relayout(cx, |options| {
+                    options.popup_maximized = !options.popup_maximized;
                     true
+                })
+            }
+
+            (Context::Popup, Action::Confirm | Action::Cancel) => cx.popup.toggle(),
+            (Context::Popup, Action::Up) => cx.popup.scroll_up(),
+            (Context::Popup, Action::Down) => cx.popup.scroll_down(),
+
+            (Context::Select, Action::Confirm) => cx.select_popup.close(),
+            (Context::Select, Action::Cancel) => cx.select_popup.close_unselected(),
+            (Context::Select, Action::Up) => cx.select_popup.prev_item(),
+            (Context::Select, Action::Down) => cx.select_popup.next_item(),
+
+            (Context::Article, Action::Confirm | Action::Cancel) => cx.article.close(),
+            (Context::Article, Action::SaveCode) => cx.article.save_code(),
+            (Context::Article, Action::Up) => cx.article.scroll_by(-1),
+            (Context::Article, Action::Down) => cx.article.scroll_by(1),
+            (Context::Article, Action::PageUp) => cx.article.scroll_by(-Self::article_page()),
+            (Context::Article, Action::PageDown) => cx.article.scroll_by(Self::article_page()),
+            (Context::Article, Action::HalfPageUp) => {
+                cx.article.scroll_by(-Self::article_page() / 2)
+            }
+            (Context::Article, Action::HalfPageDown) => {
+                cx.article.scroll_by(Self::article_page() / 2)
+            }
+            (Context::Article, Action::First) => cx.article.scroll_by(isize::MIN),
+            (Context::Article, Action::Last) => cx.article.scroll_by(isize::MAX),
+
+            (Context::Input, Action::Cancel) => cx.input.close(),
+            (Context::Input, Action::Confirm) if cx.input.is_command() => {
+                let line = cx.input.take_command().unwrap_or_default();
+                crate::command::run(cx, &line)
+            }
+            (Context::Input, Action::Confirm) => cx.input.submit(),
+            (Context::Input, Action::DeleteChar) => cx.input.remove_char(),
+            (Context::Input, Action::Complete) if cx.input.is_command() => {
+                let line = cx.input.text().cloned().unwrap_or_default();
+                let completion = cx.command.complete(&line, cx.content.get_topic().slugs());
+                cx.input.set_text(Some(completion.line));
+                if !completion.candidates.is_empty() {
+                    cx.input.set_hint(Some(completion.candidates.join(" ")));
                 }
-                _ => false,
-            };
-        }
+                true
+            }
+            (Context::Input, Action::Up) if cx.input.is_command() => {
+                if let Some(line) = cx.command.older() {
+                    cx.input.set_text(Some(line.into()));
+                }
+                true
+            }
+            (Context::Input, Action::Down) if cx.input.is_command() => {
+                if cx.command.is_browsing() {
+                    let line = cx.command.newer().map(String::from);
+                    cx.input.set_text(line);
+                }
+                true
+            }
+            (Context::Input, Action::Up | Action::Down) => {
+                cx.input.close();
+                let key = if action == Action::Up {
+                    Key::Up
+                } else {
+                    Key::Down
+                };
+                emit!(Key(key.into()));
+                true
+            }
 
-        if cx.content.get_questions().is_stats_visible() {
-            return match key {
-                Key::Char('T') => cx.content.get_topic_mut().prev_topic(),
-                Key::Char('t') => cx.content.get_topic_mut().next_topic(),
-                Key::Ctrl('s') | Key::Esc | Key::Enter => {
-                    cx.content.get_questions_mut().toggle_stats()
+            (Context::Stats | Context::List, Action::NextTopic) => {
+                cx.content.get_topic_mut().next_topic()
+            }
+            (Context::Stats | Context::List, Action::PrevTopic) => {
+                cx.content.get_topic_mut().prev_topic()
+            }
+            (Context::Stats | Context::List, Action::ToggleTopicMark) => {
+                cx.content.get_topic_mut().toggle_mark()
+            }
+            (Context::Stats | Context::List, Action::ToggleTopicSetOp) => {
+                cx.content.get_topic_mut().toggle_set_op()
+            }
+            (Context::Stats | Context::List, Action::ClearTopicMarks) => {
+                cx.content.get_topic_mut().clear_marks()
+            }
+            (Context::Stats | Context::List, Action::CycleTopicSort) => {
+                cx.content.get_topic_mut().cycle_sort()
+            }
+            (Context::Stats | Context::List, Action::NextTopicGroup) => {
+                cx.content.get_topic_mut().next_group()
+            }
+            (Context::Stats | Context::List, Action::PrevTopicGroup) => {
+                cx.content.get_topic_mut().prev_group()
+            }
+            (Context::Stats | Context::List, Action::ShowMissing) => {
+                cx.content.get_topic().show_missing()
+            }
+            (Context::List, Action::GrowTopics) => {
+                Self::relayout(cx, |options| options.grow_topics(TOPICS_STEP))
+            }
+            (Context::List, Action::ShrinkTopics) => {
+                Self::relayout(cx, |options| options.grow_topics(-TOPICS_STEP))
+            }
+            (Context::List, Action::ToggleTopics) => Self::relayout(cx, |options| {
+                options.show_topics = !options.show_topics;
+                true
+            }),
+            (Context::List, Action::TogglePreview) => Self::relayout(cx, |options| {
+                options.show_preview = !options.show_preview;
+                true
+            }),
+            (Context::List, Action::RevealHint) => {
+                // hints are revealed in the preview, shown for them if hidden
+                if !Window::default().options.show_preview {
+                    Self::relayout(cx, |options| {
+                        options.show_preview = true;
+                        true
+                    });
+                    let hovered = cx.content.get_questions().hovered();
+                    cx.preview.follow(hovered.map(|q| q.title_slug.as_str()));
                 }
-                _ => false,
-            };
+                cx.preview.reveal_hint()
+            }
+            (Context::List, Action::ShowEditorial) => cx.content.get_questions().show_editorial(),
+            (Context::List, Action::CommunitySolutions) => {
+                cx.content.get_questions().show_community_solutions()
+            }
+            (Context::List, Action::SimilarQuestions) => {
+                cx.content.get_questions().show_similar_questions()
+            }
+            (Context::List, Action::PreviewUp) => cx.preview.scroll_up(),
+            (Context::List, Action::PreviewDown) => cx.preview.scroll_down(),
+            (Context::Stats, Action::ToggleStats | Action::Confirm | Action::Cancel)
+            | (Context::List, Action::ToggleStats) => cx.content.get_questions_mut().toggle_stats(),
+
+            (Context::List, Action::OpenEditor) => {
+                cx.content.get_questions_mut().solve_for_language()
+            }
+            (Context::List, Action::Up) => cx.content.get_questions_mut().prev_ques(),
+            (Context::List, Action::Down) => cx.content.get_questions_mut().next_ques(),
+            (Context::List, Action::RandomQuestion) => cx.content.get_questions_mut().rand_ques(),
+            (Context::List, Action::JumpToQuestion) => cx.content.get_questions_mut().last_ques(),
+            (Context::List, Action::First) => cx.content.get_questions_mut().first_ques(),
+            (Context::List, Action::Last) => cx.content.get_questions_mut().last_ques(),
+            (Context::List, Action::PageUp) => cx.content.get_questions_mut().page_up(),
+            (Context::List, Action::PageDown) => cx.content.get_questions_mut().page_down(),
+            (Context::List, Action::HalfPageUp) => cx.content.get_questions_mut().half_page_up(),
+            (Context::List, Action::HalfPageDown) => {
+                cx.content.get_questions_mut().half_page_down()
+            }
+            (Context::List, Action::Confirm) => {
+                cx.content.get_questions_mut().show_question_content()
+            }
+            (Context::List, Action::Run) => cx.content.get_questions_mut().run_solution(),
+            (Context::List, Action::Submit) => cx.content.get_questions_mut().submit_solution(),
+            (Context::List, Action::History) => {
+                cx.content.get_questions_mut().show_submission_history()
+            }
+            (Context::List, Action::EditNote) => cx.content.get_questions_mut().edit_note(),
+            (Context::List, Action::AddToList) => cx.content.get_questions_mut().add_to_list(),
+            (Context::List, Action::RemoveFromList) => cx.content.remove_from_list(),
+            (Context::List, Action::Search) => cx.content.get_questions_mut().toggle_search(),
+            (Context::List, Action::CommandLine) => {
+                cx.input.reset_for_command();
+                cx.command.reset_cursor();
+                cx.input.toggle()
+            }
+
+            // bound in a context where the action does not apply
+            _ => false,
         }
+    }
 
-        if cx.content.is_visible() {
-            return match key {
-                Key::Char('T') => cx.content.get_topic_mut().prev_topic(),
-                Key::Char('t') => cx.content.get_topic_mut().next_topic(),
-                Key::Char('e') => cx.content.get_questions_mut().solve_for_language(),
-                Key::Up | Key::Char('k') => cx.content.get_questions_mut().prev_ques(),
-                Key::Down | Key::Char('j') => cx.content.get_questions_mut().next_ques(),
-                Key::Char('r') => cx.content.get_questions_mut().rand_ques(),
-                Key::Enter => cx.content.get_questions_mut().show_question_content(),
-                Key::Char('R') => cx.content.get_questions_mut().run_solution(),
-                Key::Char('s') => cx.content.get_questions_mut().submit_solution(),
-                Key::Ctrl('s') => cx.content.get_questions_mut().toggle_stats(),
-                Key::Char('/') => cx.content.get_questions_mut().toggle_search(),
-                Key::Char('q') => {
-                    emit!(Quit);
-                    false
-                }
-                _ => false,
-            };
+    /// Lines of an article shown at once.
+    fn article_page() -> isize {
+        Window::default().root.popup.inner.height.max(1) as isize
+    }
+
+    /// Changes the layout options and keeps the hovered rows in the resized
+    /// panes.
+    fn relayout(cx: &mut Ctx, update: impl FnOnce(&mut LayoutOptions) -> bool) -> bool {
+        let changed = Window::update_options(update);
+        if changed {
+            cx.content.resize();
         }
-        false
+        changed
     }
 }
@@ -1,3 +1,5 @@
+use leetcode_tui_config::CONFIG;
+use leetcode_tui_core::help::HelpLine;
 use ratatui::prelude::*;
 use ratatui::widgets::Widget;
 use ratatui::widgets::*;
@@ -16,24 +18,39 @@ impl<'a> Help<'a> {
 
 impl<'a> Widget for Help<'a> {
     fn render(self, area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
-        let selected_style = Style::default()
-            .add_modifier(Modifier::REVERSED)
-            .add_modifier(Modifier::BOLD)
-            .bg(Color::Rgb(229, 228, 226))
-            .fg(Color::Black);
-        let rows = self.cx.help.get_items().iter().map(|item| {
-            let cells = item.iter().map(|c| Cell::from(*c));
-            Row::new(cells)
-        });
+        let theme = &CONFIG.as_ref().theme.help;
+        let help = &self.cx.help;
+        let rows = help
+            .lines()
+            .into_iter()
+            .map(|line| match line {
+                HelpLine::Section(context) => {
+                    Row::new(vec![Cell::from(context.to_string())]).style(theme.section.into())
+                }
+                HelpLine::Row(row) => Row::new(vec![
+                    Cell::from(row.keys.clone()),
+                    Cell::from(row.description),
+                ])
+                .style(theme.normal.into()),
+            })
+            .collect::<Vec<_>>();
 
+        let mut title = "Help".to_string();
+        if help.is_filtering() || !help.filter().is_empty() {
+            title.push_str(&format!(" /{}", help.filter()));
+            if help.is_filtering() {
+                title.push('▏');
+            }
+        }
         let t = Table::new(rows)
-            .block(Block::default().borders(Borders::ALL).title("Help"))
-            .highlight_style(selected_style)
-            .widths(&[
-                Constraint::Percentage(50),
-                Constraint::Max(30),
-                Constraint::Min(10),
-            ]);
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.border.into())
+                    .title(title),
+            )
+            .highlight_style(theme.hovered.into())
+            .widths(&[Constraint::Percentage(30), Constraint::Percentage(70)]);
         Clear.render(area, buf);
         ratatui::widgets::StatefulWidget::render(t, area, buf, self.cx.help.get_mut_state());
     }
@@ -1,8 +1,12 @@
 pub mod app;
+pub mod article;
+pub mod cli;
+pub mod command;
 pub mod ctx;
 pub mod executor;
 pub mod help;
 pub mod popup;
+pub mod preview;
 pub mod question;
 pub mod root;
 pub mod signals;
@@ -1,14 +1,21 @@
+use clap::Parser;
 use leetcode_tui_rs::app::App;
+use leetcode_tui_rs::cli::Cli;
 use leetcode_tui_rs::utils::update_database_questions;
 use color_eyre::Result;
-use leetcode_tui_db;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
     leetcode_tui_db::init();
 
     leetcode_tui_config::init(leetcode_tui_db::DB_BUILDER.as_ref()).await?;
 
+    if let Some(command) = cli.command {
+        return command.run(leetcode_tui_config::DB_CLIENT.as_ref());
+    }
+
     leetcode_tui_core::init();
 
     update_database_questions().await?;
@@ -61,7 +61,7 @@ pub struct Popup<'a> {
 }
 
 impl<'a> Popup<'a> {
-    pub fn prepare_lines(&self) -> Vec<Line> {
+    pub fn prepare_lines(&self) -> Vec<Line<'_>> {
         self.ctx
             .popup
             .get_lines()
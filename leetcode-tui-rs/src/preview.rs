@@ -0,0 +1,72 @@
+use leetcode_tui_config::CONFIG;
+use leetcode_tui_core::preview::PreviewLine;
+use leetcode_tui_core::utils::wrap;
+use leetcode_tui_shared::layout::GetWindowStats;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph, Widget};
+
+use crate::ctx::Ctx;
+
+/// Description of the hovered question, below the Questions pane.
+pub struct Preview<'a> {
+    cx: &'a Ctx,
+}
+
+impl<'a> Preview<'a> {
+    pub(super) fn new(cx: &'a Ctx) -> Self {
+        Self { cx }
+    }
+
+    /// Rows of the preview wrapped to `width`, the rows
+    /// [`scroll_down`](leetcode_tui_core::preview::Preview::scroll_down)
+    /// counts.
+    fn rows(&self, width: u16) -> Vec<Line<'static>> {
+        let c_def = &CONFIG.as_ref().theme.defaults;
+        let heading = Style::default()
+            .fg(c_def.info.into())
+            .add_modifier(Modifier::BOLD);
+        let muted = Style::default().fg(c_def.comment.into());
+        let error: Style = CONFIG.as_ref().theme.question.normal.hard.into();
+        let mut rows = vec![];
+        for line in self.cx.preview.lines() {
+            let style = match line {
+                PreviewLine::Muted(_) => muted,
+                PreviewLine::Heading(_) => heading,
+                PreviewLine::Text(_) => Style::default(),
+                PreviewLine::Error(_) => error,
+            };
+            rows.extend(
+                wrap(line.text(), width)
+                    .into_iter()
+                    .map(|row| Line::styled(row.to_string(), style)),
+            );
+        }
+        rows
+    }
+}
+
+impl<'a> Widget for Preview<'a> {
+    fn render(self, _area: Rect, buf: &mut Buffer) {
+        let areas = self.get_window().root.center_layout.preview;
+        if areas.outer.area() == 0 {
+            return;
+        }
+        let title = self
+            .cx
+            .content
+            .get_questions()
+            .hovered()
+            .map_or_else(|| "Preview".to_string(), |q| q.title.clone());
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(CONFIG.as_ref().theme.border.normal.into())
+            .cyan()
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .render(areas.outer, buf);
+        Paragraph::new(self.rows(areas.inner.width))
+            .scroll((self.cx.preview.scroll(), 0))
+            .style(Style::default().fg(CONFIG.as_ref().theme.defaults.fg.into()))
+            .render(areas.inner, buf);
+    }
+}
@@ -4,6 +4,7 @@ use ratatui::widgets::{Block, BorderType, Borders, List, ListItem, Widget};
 use leetcode_tui_shared::layout::GetWindowStats;
 
 use crate::ctx::Ctx;
+use crate::utils::render_scrollbar;
 
 pub(crate) mod stats;
 
@@ -83,8 +84,11 @@ impl<'a> Widget for Questions<'a> {
         if let Some(ql) = self.get_questions_list() {
             let list = List::new(ql);
             list.render(term_window.root.center_layout.question.inner, buf);
+            if let Some(state) = self.cx.content.get_questions().scrollbar() {
+                render_scrollbar(state, term_window.root.center_layout.question.outer, buf);
+            }
             if self.cx.content.get_questions().is_stats_visible() {
-                stats::Stats::new(&self.cx.content.get_questions())
+                stats::Stats::new(self.cx.content.get_questions())
                     .render(term_window.root.q_stats.outer, buf);
             }
         }
@@ -13,7 +13,7 @@ impl<'a> Stats<'a> {
     }
 }
 
-fn create_gauge(title: &str, val: usize, total: usize, style: Style) -> Gauge {
+fn create_gauge(title: &str, val: usize, total: usize, style: Style) -> Gauge<'_> {
     let block_title = format!("{}: {}/{}", title, val, total);
     let percentage = if total != 0 {
         (val as f32 / total as f32) * 100_f32
@@ -35,7 +35,7 @@ fn create_gauge(title: &str, val: usize, total: usize, style: Style) -> Gauge {
 }
 
 impl<'a> Stats<'a> {
-    fn create_block(title: &str) -> Block {
+    fn create_block(title: &str) -> Block<'_> {
         Block::default()
             .borders(Borders::ALL)
             .title(Span::styled(
@@ -1,11 +1,13 @@
 use leetcode_tui_config::CONFIG;
+use leetcode_tui_shared::layout::{GetWindowStats, MIN_HEIGHT, MIN_WIDTH};
 use ratatui::prelude::*;
 use ratatui::widgets::*;
-use leetcode_tui_shared::layout::GetWindowStats;
 
+use crate::article::Article;
 use crate::ctx::Ctx;
 use crate::help::Help;
 use crate::popup::{Popup, SelectPopup};
+use crate::preview::Preview;
 use crate::question::Questions;
 use crate::topic::Topic;
 
@@ -21,23 +23,39 @@ impl<'a> Root<'a> {
 
 impl<'a> Widget for Root<'a> {
     fn render(self, _area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
+        let window = self.get_window();
+        if window.is_too_small() {
+            too_small(window.area, buf);
+            return;
+        }
         if self.cx.help.is_visible() {
             Help::new(self.cx).render(_area, buf);
             return;
         } else {
-            let line = Line::from(vec![Span::styled(
+            let mut spans = vec![];
+            let mut typed: String = self.cx.count.map(|c| c.to_string()).unwrap_or_default();
+            typed.extend(self.cx.pending.iter().map(ToString::to_string));
+            if !typed.is_empty() {
+                spans.push(Span::raw(format!("{typed} ")));
+            }
+            spans.push(Span::styled(
                 " [?] Help ",
                 Style::default()
                     .bg(CONFIG.as_ref().theme.defaults.info.into())
                     .fg(CONFIG.as_ref().theme.defaults.terminal_black.into()),
-            )]);
-            Paragraph::new(line)
+            ));
+            Paragraph::new(Line::from(spans))
                 .alignment(Alignment::Right)
                 .render(self.get_window().root.status_bar.message_area, buf);
         }
 
         Topic::new(self.cx).render(_area, buf);
         Questions::new(self.cx).render(_area, buf);
+        Preview::new(self.cx).render(_area, buf);
+
+        if self.cx.article.visible {
+            Article::new(self.cx).render(_area, buf);
+        }
 
         if self.cx.popup.visible {
             Popup::new(self.cx).render(_area, buf);
@@ -48,12 +66,45 @@ impl<'a> Widget for Root<'a> {
         }
 
         if self.cx.input.visible {
-            let mut search_text: String = "/".into();
+            let mut search_text: String = self.cx.input.prompt().into();
             if let Some(input_text) = self.cx.input.text() {
                 search_text.push_str(input_text);
             }
-            let line = Line::from(search_text.as_str());
-            Paragraph::new(line).render(self.get_window().root.status_bar.search_area, buf);
+            let mut spans = vec![Span::raw(search_text)];
+            if let Some(hint) = self.cx.input.hint() {
+                spans.push(Span::styled(
+                    format!("  {hint}"),
+                    Style::default().fg(CONFIG.as_ref().theme.defaults.info.into()),
+                ));
+            }
+            if let Some(error) = self.cx.input.error() {
+                spans.push(Span::styled(
+                    format!("  {error}"),
+                    CONFIG.as_ref().theme.question.normal.hard.into(),
+                ));
+            }
+            Paragraph::new(Line::from(spans))
+                .render(self.get_window().root.status_bar.search_area, buf);
         }
     }
 }
+
+/// Shown instead of the panes when the terminal cannot fit them.
+fn too_small(area: Rect, buf: &mut Buffer) {
+    let c_def = &CONFIG.as_ref().theme.defaults;
+    let lines = vec![
+        Line::styled("Terminal too small", Style::default().fg(c_def.info.into())),
+        Line::from(format!("{}x{}", area.width, area.height)),
+        Line::from(format!("needs {MIN_WIDTH}x{MIN_HEIGHT}")),
+    ];
+    let top = area.height.saturating_sub(lines.len() as u16) / 2;
+    let area = Rect {
+        y: area.y + top,
+        height: area.height - top,
+        ..area
+    };
+    Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .render(area, buf);
+}
@@ -1,7 +1,9 @@
 use leetcode_tui_core::Event;
 
 use color_eyre::Result;
-use crossterm::event::{Event as CrosstermEvent, EventStream, KeyEvent, KeyEventKind};
+use crossterm::event::{
+    Event as CrosstermEvent, EventStream, KeyEvent, KeyEventKind, MouseEvent, MouseEventKind,
+};
 use futures::StreamExt;
 use tokio::{
     sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
@@ -72,6 +74,16 @@ impl Signals {
                                     Event::Key(key)
                                 }
                             },
+                            // moves, drags and releases are not used and
+                            // would only cause redraws
+                            CrosstermEvent::Mouse(
+                                mouse @ MouseEvent {
+                                    kind: MouseEventKind::Down(_)
+                                        | MouseEventKind::ScrollUp
+                                        | MouseEventKind::ScrollDown,
+                                    ..
+                                },
+                            ) => Event::Mouse(mouse),
                             // CrosstermEvent::Paste(str) => Event::Paste(str),
                             CrosstermEvent::Resize(cols, rows) => Event::Resize(cols, rows),
                             _ => continue,
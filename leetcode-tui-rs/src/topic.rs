@@ -1,9 +1,14 @@
+use leetcode_tui_config::keymap::Action;
 use leetcode_tui_config::CONFIG;
+use leetcode_tui_core::content::topic::{TopicGroup, TopicRow};
+use leetcode_tui_db::{DbTopicStats, DUE_FOR_REVIEW};
+use leetcode_tui_shared::layout::GetWindowStats;
 use ratatui::prelude::*;
+use ratatui::widgets::block::{Position, Title};
 use ratatui::widgets::{Block, Borders, List, ListItem, Widget};
-use leetcode_tui_shared::layout::GetWindowStats;
 
 use crate::ctx::Ctx;
+use crate::utils::render_scrollbar;
 
 pub struct Topic<'a> {
     cx: &'a Ctx,
@@ -14,31 +19,133 @@ impl<'a> Topic<'a> {
         Self { cx }
     }
 
-    fn get_styled_block(&self) -> Block {
-        Block::default()
+    fn get_styled_block(&self) -> Block<'_> {
+        let topic = self.cx.content.get_topic();
+        let mut title = "Topics".to_string();
+        if let Some(group) = topic.hovered().and_then(TopicGroup::of_topic) {
+            title.push_str(&format!(" · {group}"));
+        }
+        if !topic.marked().is_empty() {
+            title.push_str(&format!(
+                " ({} marked, {})",
+                topic.marked().len(),
+                topic.set_op()
+            ));
+        }
+        if topic.sort() != Default::default() {
+            title.push_str(&format!(" by {}", topic.sort()));
+        }
+        let mut block = Block::default()
             .borders(Borders::ALL)
             .border_style(CONFIG.as_ref().theme.border.normal.into())
             .cyan()
-            .title("Topics")
-            .title_alignment(Alignment::Center)
+            .title(title)
+            .title_alignment(Alignment::Center);
+        if let Some(curated) = topic.hovered().and_then(|t| topic.curated(t)) {
+            let keys = CONFIG.as_ref().keymap.list.keys(Action::ShowMissing);
+            if let Some(keys) = keys.filter(|_| !curated.missing.is_empty()) {
+                block = block.title(
+                    Title::from(format!(" {keys} lists the missing "))
+                        .position(Position::Bottom)
+                        .alignment(Alignment::Center),
+                );
+            }
+        } else if let Some(stats) = topic.hovered().and_then(|t| topic.stats(t)) {
+            block = block.title(
+                Title::from(format!(
+                    " ✓{} ~{} /{} ",
+                    stats.solved, stats.attempted, stats.total
+                ))
+                .position(Position::Bottom)
+                .alignment(Alignment::Center),
+            );
+        }
+        block
+    }
+}
+
+const BAR_WIDTH: usize = 5;
+
+/// Renders `solved` and `attempted` as parts of a fixed width bar.
+fn progress_bar(stats: &DbTopicStats) -> String {
+    let cells = |n: u32| {
+        if stats.total == 0 {
+            0
+        } else {
+            (n as usize * BAR_WIDTH + stats.total as usize / 2) / stats.total as usize
+        }
+    };
+    let solved = cells(stats.solved).min(BAR_WIDTH);
+    let attempted = cells(stats.attempted).min(BAR_WIDTH - solved);
+    format!(
+        "{}{}{}",
+        "█".repeat(solved),
+        "▒".repeat(attempted),
+        "░".repeat(BAR_WIDTH - solved - attempted)
+    )
+}
+
+/// Left aligned topic slug followed by its right aligned progress, truncating
+/// the slug when the pane is too narrow.
+fn topic_line(marker: &str, name: &str, stats: Option<&DbTopicStats>, width: usize) -> String {
+    let Some(stats) = stats else {
+        return format!("{marker}{name}");
+    };
+    let progress = format!("{} {}/{}", progress_bar(stats), stats.solved, stats.total);
+    let room = width.saturating_sub(marker.chars().count() + progress.chars().count() + 1);
+    let mut label: String = name.chars().take(room).collect();
+    if name.chars().count() > room && room > 0 {
+        label.pop();
+        label.push('…');
     }
+    let pad = room.saturating_sub(label.chars().count()) + 1;
+    format!("{marker}{label}{}{progress}", " ".repeat(pad))
 }
 
 impl<'a> Widget for Topic<'a> {
     fn render(self, _area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
+        // hidden with `show_topics`
+        if self.get_window().root.center_layout.topic.outer.area() == 0 {
+            return;
+        }
         if let Some(hovered) = self.cx.content.get_topic().hovered() {
             let config = &CONFIG.as_ref().theme.topic;
             let c_hovered = config.hovered.into();
-            let normal = config.normal.into();
+            let normal: Style = config.normal.into();
 
-            let lines = self
-                .cx
-                .content
-                .get_topic()
+            let topic = self.cx.content.get_topic();
+            let width = self.get_window().root.center_layout.topic.inner.width as usize;
+
+            let lines = topic
                 .window()
                 .iter()
-                .map(|t| {
-                    ListItem::new(t.slug.as_str()).style(if t.slug == hovered.slug {
+                .map(|row| {
+                    let t = match row {
+                        TopicRow::Topic(t) => t,
+                        TopicRow::Header(group) => {
+                            return ListItem::new(format!("{:─<width$}", format!("─ {group} ")))
+                                .style(normal.add_modifier(Modifier::BOLD));
+                        }
+                    };
+                    let marker = if topic.is_marked(t) { "● " } else { "  " };
+                    let line = if t.slug == DUE_FOR_REVIEW {
+                        format!("{marker}{} ({})", t.name, topic.due_count())
+                    } else if let Some(curated) = topic.curated(t) {
+                        let found = curated.question_ids.len();
+                        match curated.missing.len() {
+                            0 => format!("{marker}★ {} ({found})", t.name),
+                            missing => format!(
+                                "{marker}★ {} ({found}/{}, {missing} missing)",
+                                t.name,
+                                found + missing
+                            ),
+                        }
+                    } else if let Some(len) = topic.list_len(t) {
+                        format!("{marker}☰ {} ({len})", t.name)
+                    } else {
+                        topic_line(marker, &t.name, topic.stats(t), width)
+                    };
+                    ListItem::new(line).style(if t.slug == hovered.slug {
                         c_hovered
                     } else {
                         normal
@@ -49,6 +156,9 @@ impl<'a> Widget for Topic<'a> {
                 .render(self.get_window().root.center_layout.topic.outer, buf);
             let list = List::new(lines);
             list.render(self.get_window().root.center_layout.topic.inner, buf);
+            if let Some(state) = topic.scrollbar() {
+                render_scrollbar(state, self.get_window().root.center_layout.topic.outer, buf);
+            }
         }
     }
 }
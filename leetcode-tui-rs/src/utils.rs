@@ -1,9 +1,11 @@
 use api::{Client, GQLLeetcodeRequest, QuestionRequest};
 use color_eyre::Result;
-use leetcode_tui_config::clients::Db;
 use kdam::BarExt;
 use leetcode_core as api;
+use leetcode_tui_config::clients::Db;
 use leetcode_tui_db::DbQuestion;
+use ratatui::prelude::*;
+use ratatui::widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget};
 
 pub async fn update_database_questions() -> Result<()> {
     let client: &Client = leetcode_tui_config::REQ_CLIENT.as_ref();
@@ -27,9 +29,25 @@ pub async fn update_database_questions() -> Result<()> {
         db_question_count, total_questions
     );
 
+    let mut pb = kdam::tqdm!(total = total_questions as usize);
+    sync_questions(|saved| {
+        pb.update(saved)?;
+        Ok(())
+    })
+    .await?;
+    eprintln!();
+
+    Ok(())
+}
+
+/// Downloads every page of questions into the database, calling `on_page`
+/// with the number of questions saved from each page. Returns the total.
+pub async fn sync_questions(mut on_page: impl FnMut(usize) -> Result<()>) -> Result<usize> {
+    let client: &Client = leetcode_tui_config::REQ_CLIENT.as_ref();
+    let database_client: &Db = leetcode_tui_config::DB_CLIENT.as_ref();
     let mut skip = 0;
     let chunk_size = 100;
-    let mut pb = kdam::tqdm!(total = total_questions as usize);
+    let mut total = 0;
 
     loop {
         let resp = QuestionRequest::new(chunk_size, skip).send(client).await?;
@@ -37,17 +55,30 @@ pub async fn update_database_questions() -> Result<()> {
         if questions.is_empty() {
             break;
         }
-        let mut db_questions = questions
+        let db_questions = questions
             .into_iter()
             .map(|q| q.try_into().unwrap())
             .collect::<Vec<DbQuestion>>();
-        for question in db_questions.iter_mut() {
-            question.save_to_db(database_client)?;
-            pb.update(1)?;
-        }
+        DbQuestion::save_many(&db_questions, database_client)?;
+        on_page(db_questions.len())?;
+        total += db_questions.len();
         skip += chunk_size;
     }
-    eprintln!();
 
-    Ok(())
+    Ok(total)
+}
+
+/// Draws a scrollbar over the right border of a bordered block.
+pub(crate) fn render_scrollbar(mut state: ScrollbarState, outer: Rect, buf: &mut Buffer) {
+    Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .render(
+            outer.inner(&Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            buf,
+            &mut state,
+        );
 }
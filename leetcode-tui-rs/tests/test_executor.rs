@@ -0,0 +1,76 @@
+use leetcode_tui_config::constants::CONFIG_FOLDER;
+use leetcode_tui_config::key::Key;
+use leetcode_tui_config::{CONFIG, DB_CLIENT};
+use leetcode_tui_core::command::CommandLine;
+use leetcode_tui_core::content::MainContent;
+use leetcode_tui_core::Event;
+use leetcode_tui_db::models::question::DbQuestion;
+use leetcode_tui_db::DB_BUILDER;
+use leetcode_tui_rs::ctx::Ctx;
+use leetcode_tui_rs::executor::Executor;
+use leetcode_tui_shared::layout::Window;
+
+fn hovered_id(cx: &Ctx) -> Option<u32> {
+    cx.content.get_questions().hovered().map(|q| q.id)
+}
+
+#[tokio::test]
+async fn test_list_motions_should_move_the_hovered_question() {
+    // the default config points into the config folder
+    CONFIG_FOLDER.get_or_init(|| Some(std::env::temp_dir()));
+    CONFIG.init(Default::default());
+    let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+    Event::init(tx);
+    leetcode_tui_db::init();
+    DB_CLIENT.init(DB_BUILDER.as_ref().create_in_memory().unwrap());
+    Window::resize(120, 40);
+    let mut cx = Ctx {
+        content: MainContent::new().await,
+        popup: Default::default(),
+        select_popup: Default::default(),
+        input: Default::default(),
+        help: Default::default(),
+        article: Default::default(),
+        preview: Default::default(),
+        command: CommandLine::default(),
+        count: None,
+        pending: vec![],
+        last_click: None,
+    };
+    let questions = (1..=100)
+        .map(|id| {
+            DbQuestion::new(
+                id,
+                "title",
+                &format!("slug-{id}"),
+                "Easy".into(),
+                false,
+                None,
+            )
+        })
+        .collect();
+    cx.content.get_questions_mut().set_questions(questions);
+    let page = Window::default().root.center_layout.question.inner.height as u32;
+    assert_eq!(hovered_id(&cx), Some(1));
+
+    assert!(Executor::handle(&mut cx, Key::End));
+    assert_eq!(hovered_id(&cx), Some(100));
+    assert!(Executor::handle(&mut cx, Key::Home));
+    assert_eq!(hovered_id(&cx), Some(1));
+
+    assert!(Executor::handle(&mut cx, Key::PageDown));
+    assert_eq!(hovered_id(&cx), Some(1 + page));
+    assert!(Executor::handle(&mut cx, Key::Ctrl('b')));
+    assert_eq!(hovered_id(&cx), Some(1));
+
+    // a count repeats the half page motion
+    assert!(Executor::handle(&mut cx, Key::Char('2')));
+    assert!(Executor::handle(&mut cx, Key::Ctrl('d')));
+    assert_eq!(hovered_id(&cx), Some(1 + 2 * (page / 2)));
+    assert!(Executor::handle(&mut cx, Key::Ctrl('u')));
+    assert_eq!(hovered_id(&cx), Some(1 + page / 2));
+
+    assert!(Executor::handle(&mut cx, Key::Char('g')));
+    assert!(Executor::handle(&mut cx, Key::Char('g')));
+    assert_eq!(hovered_id(&cx), Some(1));
+}
@@ -58,11 +58,20 @@
 // │  /search                                                      [?] Help  │
 // └─────────────────────────────────────────────────────────────────────────┘
 
+use std::sync::{PoisonError, RwLock};
+
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Widget},
 };
 
+/// Smallest terminal the panes are drawn in, smaller ones get a notice.
+pub const MIN_WIDTH: u16 = 40;
+pub const MIN_HEIGHT: u16 = 12;
+
+/// Layout of the terminal, computed on start and on each resize.
+static LAYOUT: RwLock<Option<Window>> = RwLock::new(None);
+
 #[derive(Debug, Clone)]
 pub struct BlockAreas {
     pub inner: Rect,
@@ -78,12 +87,138 @@ impl From<Rect> for BlockAreas {
     }
 }
 
-#[derive(Debug)]
+impl BlockAreas {
+    /// Whether the cell at `column`, `row` of the terminal is inside the
+    /// block, borders included.
+    pub fn contains(&self, column: u16, row: u16) -> bool {
+        contains(self.outer, column, row)
+    }
+
+    /// Row of the content at `column`, `row` of the terminal, counted from
+    /// the top of the inner area. `None` outside of it.
+    pub fn inner_row(&self, column: u16, row: u16) -> Option<usize> {
+        contains(self.inner, column, row).then(|| (row - self.inner.y) as usize)
+    }
+}
+
+/// How the panes are arranged. Set from the `[layout]` config section and
+/// changed with keys at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutOptions {
+    /// Share of the width (the height when stacked) given to the Topics
+    /// pane, in percent.
+    pub topics_percent: u16,
+    /// Width and height of popups, in percent of the terminal.
+    pub popup_percent: u16,
+    pub show_topics: bool,
+    /// Splits a pane previewing the hovered question off the bottom of the
+    /// Questions pane.
+    pub show_preview: bool,
+    /// Share of the Questions pane height given to the preview, in percent.
+    pub preview_percent: u16,
+    /// Popups cover everything but the title and status bars.
+    pub popup_maximized: bool,
+    /// Terminals narrower than this stack the Topics pane above the
+    /// Questions pane.
+    pub stack_below: u16,
+}
+
+impl LayoutOptions {
+    pub const MIN_TOPICS_PERCENT: u16 = 10;
+    pub const MAX_TOPICS_PERCENT: u16 = 60;
+
+    /// Widens the Topics pane by `delta` percent, within the bounds.
+    pub fn grow_topics(&mut self, delta: i16) -> bool {
+        let percent = self
+            .topics_percent
+            .saturating_add_signed(delta)
+            .clamp(Self::MIN_TOPICS_PERCENT, Self::MAX_TOPICS_PERCENT);
+        let changed = self.show_topics && percent != self.topics_percent;
+        if changed {
+            self.topics_percent = percent;
+        }
+        changed
+    }
+}
+
+impl Default for LayoutOptions {
+    fn default() -> Self {
+        Self {
+            topics_percent: 20,
+            popup_percent: 60,
+            show_topics: true,
+            show_preview: false,
+            preview_percent: 50,
+            popup_maximized: false,
+            stack_below: 80,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Window {
     pub root: Root,
+    /// The whole terminal.
+    pub area: Rect,
+    pub options: LayoutOptions,
+}
+
+impl Window {
+    fn new(area: Rect, options: LayoutOptions) -> Self {
+        Self {
+            root: Root::new(area, &options),
+            area,
+            options,
+        }
+    }
+
+    /// Recomputes the layout for a terminal of `columns` by `rows` cells.
+    pub fn resize(columns: u16, rows: u16) -> Self {
+        let options = Self::cached().map(|w| w.options).unwrap_or_default();
+        Self::store(Self::new(Rect::new(0, 0, columns, rows), options))
+    }
+
+    /// Recomputes the layout of the current terminal with new options.
+    pub fn set_options(options: LayoutOptions) -> Self {
+        let area = Self::cached().map_or_else(
+            || {
+                let term_size = super::tui::Term::size();
+                Rect::new(0, 0, term_size.columns, term_size.rows)
+            },
+            |w| w.area,
+        );
+        Self::store(Self::new(area, options))
+    }
+
+    /// Changes the options of the current layout with `update`, returning
+    /// whether they changed.
+    pub fn update_options(update: impl FnOnce(&mut LayoutOptions) -> bool) -> bool {
+        let mut options = Self::default().options;
+        let changed = update(&mut options);
+        if changed {
+            Self::set_options(options);
+        }
+        changed
+    }
+
+    pub fn is_too_small(&self) -> bool {
+        self.area.width < MIN_WIDTH || self.area.height < MIN_HEIGHT
+    }
+
+    fn cached() -> Option<Self> {
+        LAYOUT
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn store(window: Self) -> Self {
+        *LAYOUT.write().unwrap_or_else(PoisonError::into_inner) = Some(window.clone());
+        window
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StatusBar {
     pub search_area: Rect,
     pub message_area: Rect,
@@ -102,7 +237,7 @@ impl From<Rect> for StatusBar {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Root {
     pub top_bar: Rect,
     pub center_layout: CenterLayout,
@@ -111,30 +246,68 @@ pub struct Root {
     pub q_stats: BlockAreas,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CenterLayout {
     pub question: BlockAreas,
     pub topic: BlockAreas,
+    /// Empty unless the preview is shown.
+    pub preview: BlockAreas,
 }
 
 impl CenterLayout {
-    fn new(chunks: Rect) -> Self {
-        let center_chunks = Layout::new()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(20), Constraint::Percentage(80)].as_ref())
-            .split(chunks);
-
-        let topic_area = center_chunks[0];
-        let question_area = center_chunks[1];
+    fn new(chunks: Rect, options: &LayoutOptions) -> Self {
+        let (question_area, topic_area) = if options.show_topics {
+            Self::split_topics(chunks, options)
+        } else {
+            (chunks, Rect::new(chunks.x, chunks.y, 0, 0))
+        };
+        let (question_area, preview_area) = if options.show_preview {
+            let split = Layout::new()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Percentage(100 - options.preview_percent),
+                        Constraint::Percentage(options.preview_percent),
+                    ]
+                    .as_ref(),
+                )
+                .split(question_area);
+            (split[0], split[1])
+        } else {
+            (question_area, Rect::new(chunks.x, chunks.y, 0, 0))
+        };
         Self {
             question: question_area.into(),
             topic: topic_area.into(),
+            preview: preview_area.into(),
         }
     }
+
+    /// Areas of the Questions and Topics panes side by side, or stacked on
+    /// narrow terminals.
+    fn split_topics(chunks: Rect, options: &LayoutOptions) -> (Rect, Rect) {
+        let direction = if chunks.width < options.stack_below {
+            Direction::Vertical
+        } else {
+            Direction::Horizontal
+        };
+        let center_chunks = Layout::new()
+            .direction(direction)
+            .constraints(
+                [
+                    Constraint::Percentage(options.topics_percent),
+                    Constraint::Percentage(100 - options.topics_percent),
+                ]
+                .as_ref(),
+            )
+            .split(chunks);
+
+        (center_chunks[1], center_chunks[0])
+    }
 }
 
 impl Root {
-    fn new(ar: Rect) -> Self {
+    fn new(ar: Rect, options: &LayoutOptions) -> Self {
         let chunks = Layout::new()
             .direction(Direction::Vertical)
             .constraints(
@@ -147,25 +320,29 @@ impl Root {
             )
             .split(ar);
 
-        let mut r = Self {
+        let popup = if options.popup_maximized {
+            chunks[1]
+        } else {
+            centered_rect(options.popup_percent, options.popup_percent, ar)
+        };
+        let center_layout = CenterLayout::new(chunks[1], options);
+        Self {
             top_bar: chunks[0],
-            center_layout: CenterLayout::new(chunks[1]),
             status_bar: chunks[2].into(),
-            popup: centered_rect(60, 60, ar).into(),
-            q_stats: centered_rect(60, 60, ar).into(),
-        };
-        r.q_stats = r.center_layout.question.clone();
-        r
+            popup: popup.into(),
+            q_stats: center_layout.question.clone(),
+            center_layout,
+        }
     }
 }
 
 impl Default for Window {
+    /// The cached layout, computed from the terminal size the first time.
     fn default() -> Self {
-        let term_size = super::tui::Term::size();
-        let window_rect = Rect::new(0, 0, term_size.columns, term_size.rows);
-        Self {
-            root: Root::new(window_rect),
-        }
+        Self::cached().unwrap_or_else(|| {
+            let term_size = super::tui::Term::size();
+            Self::resize(term_size.columns, term_size.rows)
+        })
     }
 }
 
@@ -187,6 +364,10 @@ impl Blockify for Rect {
     }
 }
 
+fn contains(rect: Rect, column: u16, row: u16) -> bool {
+    (rect.x..rect.right()).contains(&column) && (rect.y..rect.bottom()).contains(&row)
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
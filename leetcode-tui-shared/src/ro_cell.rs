@@ -32,6 +32,12 @@ impl<T> RoCell<T> {
     }
 }
 
+impl<T> Default for RoCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> AsRef<T> for RoCell<T> {
     fn as_ref(&self) -> &T {
         unsafe { (*self.0.get()).as_ref().unwrap() }
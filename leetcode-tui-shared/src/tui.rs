@@ -1,6 +1,7 @@
 use crossterm::terminal::WindowSize;
 use crossterm::{
     cursor,
+    event::{DisableMouseCapture, EnableMouseCapture},
     terminal::{EnterAlternateScreen, LeaveAlternateScreen},
 };
 use std::mem;
@@ -12,6 +13,8 @@ use std::{
 use color_eyre::eyre::Result;
 use ratatui::{prelude::CrosstermBackend as Backend, Terminal};
 
+use crate::layout::Window;
+
 pub type Frame<'a> = ratatui::Frame<'a, Backend<std::io::Stdout>>;
 
 pub struct Term {
@@ -28,14 +31,24 @@ impl Term {
 
     pub fn enter(&mut self) -> Result<()> {
         crossterm::terminal::enable_raw_mode()?;
-        crossterm::execute!(std::io::stdout(), EnterAlternateScreen, cursor::Hide)?;
+        crossterm::execute!(
+            std::io::stdout(),
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            cursor::Hide
+        )?;
         Ok(())
     }
 
     pub fn exit(&mut self) -> Result<()> {
         if crossterm::terminal::is_raw_mode_enabled()? {
             self.flush()?;
-            crossterm::execute!(std::io::stdout(), LeaveAlternateScreen, cursor::Show)?;
+            crossterm::execute!(
+                std::io::stdout(),
+                DisableMouseCapture,
+                LeaveAlternateScreen,
+                cursor::Show
+            )?;
             crossterm::terminal::disable_raw_mode()?;
         }
         Ok(())
@@ -50,6 +63,8 @@ impl Term {
         self.enter()?;
         let size = self.size()?;
         self.resize(size)?;
+        // the terminal may have been resized while suspended
+        Window::resize(size.width, size.height);
         Ok(())
     }
 